@@ -0,0 +1,36 @@
+use aoc2023_day01::*;
+
+// Counts allocations with `--features alloc`, the same way the runner does.
+#[cfg(feature = "alloc")]
+#[global_allocator]
+static ALLOC: aoc_common::alloc::CountingAlloc = aoc_common::alloc::CountingAlloc::new();
+
+fn main() {
+    #[cfg(feature = "alloc")]
+    {
+        let input = include_str!("../input.txt");
+        println!("parse: {}", ALLOC.measure(|| aoc2023_day01::parse(input)));
+        let input = aoc2023_day01::parse(input).unwrap();
+        println!("part1: {}", ALLOC.measure(|| part1::solve(&input)));
+        println!("part2: {}", ALLOC.measure(|| part2::solve(&input)));
+    }
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn parse() {
+    aoc2023_day01::parse(divan::black_box(include_str!("../input.txt",))).unwrap();
+}
+
+#[divan::bench]
+fn part1(bencher: divan::Bencher) {
+    let input = aoc2023_day01::parse(include_str!("../input.txt",)).unwrap();
+    bencher.bench(|| part1::solve(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part2(bencher: divan::Bencher) {
+    let input = aoc2023_day01::parse(include_str!("../input.txt",)).unwrap();
+    bencher.bench(|| part2::solve(divan::black_box(&input)).unwrap());
+}
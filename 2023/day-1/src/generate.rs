@@ -0,0 +1,40 @@
+use aoc_common::puzzle::Puzzle;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use crate::WORDS;
+
+/// Letters that appear in none of the spelled-out digits, so filler can never spell one.
+const FILLER: &[u8] = b"abcdjklmpqyz";
+
+/// `size` lines of filler letters, digits and spelled-out digits. Every line has at least
+/// one real digit, so part 1 always has a value. Whole words and filler never run together
+/// into another word, so both answers are known as the lines are built.
+pub fn puzzle(size: usize, seed: u64) -> Puzzle {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0, 0);
+    for _ in 0..size {
+        let mut line = String::new();
+        let (mut digits, mut with_words) = (vec![], vec![]);
+        let tokens = rng.gen_range(1..=6);
+        let real = rng.gen_range(0..tokens);
+        for index in 0..tokens {
+            for _ in 0..rng.gen_range(0..4) {
+                line.push(*FILLER.choose(&mut rng).unwrap() as char);
+            }
+            let digit = rng.gen_range(1..=9);
+            if index == real || rng.gen_bool(0.5) {
+                line.push(char::from_digit(digit, 10).unwrap());
+                digits.push(digit);
+            } else {
+                line.push_str(WORDS[digit as usize - 1]);
+            }
+            with_words.push(digit);
+        }
+        part1 += digits[0] * 10 + digits[digits.len() - 1];
+        part2 += with_words[0] * 10 + with_words[with_words.len() - 1];
+        input.push_str(&line);
+        input.push('\n');
+    }
+    Puzzle::new(input).with_part1(part1).with_part2(part2)
+}
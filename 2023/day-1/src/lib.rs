@@ -0,0 +1,81 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+#[cfg(test)]
+aoc_common::example_tests!();
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The digits in a line in the order they appear. With `words` set, spelled-out digits
+/// count too, including overlapping ones such as the `eight` and `two` in `eightwo`.
+pub fn digits(line: &str, words: bool) -> impl Iterator<Item = u32> + '_ {
+    line.char_indices().filter_map(move |(index, c)| {
+        let rest = &line[index..];
+        c.to_digit(10).or_else(|| {
+            if !words {
+                return None;
+            }
+            WORDS
+                .iter()
+                .position(|word| rest.starts_with(word))
+                .map(|position| position as u32 + 1)
+        })
+    })
+}
+
+/// The first and last digits of a line combined into a two-digit number.
+pub fn calibration_value(line: &str, words: bool) -> Option<u32> {
+    let mut digits = digits(line, words);
+    let first = digits.next()?;
+    let last = digits.last().unwrap_or(first);
+    Some(first * 10 + last)
+}
+
+/// The calibration document, one line of text per entry.
+pub type Input = Vec<String>;
+
+#[tracing::instrument(skip(input), fields(bytes = input.len(), lines = input.lines().count()))]
+pub fn parse(input: &str) -> miette::Result<Input> {
+    Ok(input.lines().map(str::to_string).collect())
+}
+
+/// Sum of the calibration values of every line.
+pub fn calibration_sum(lines: &[String], words: bool) -> miette::Result<u32> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            calibration_value(line, words)
+                .ok_or_else(|| miette::miette!("line {}: no digit found in {line:?}", index + 1))
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("two1nine", 29)]
+    #[case("eightwothree", 83)]
+    #[case("abcone2threexyz", 13)]
+    #[case("xtwone3four", 24)]
+    #[case("4nineeightseven2", 42)]
+    #[case("zoneight234", 14)]
+    #[case("7pqrstsixteen", 76)]
+    #[case("eightwo", 82)]
+    #[case("ñ1dos→nine", 19)]
+    fn test_word_digits(#[case] line: &str, #[case] expected: u32) {
+        assert_eq!(Some(expected), calibration_value(line, true));
+    }
+
+    #[test]
+    fn test_missing_digit() {
+        assert_eq!(None, calibration_value("one", false));
+        assert!(calibration_sum(&parse("1abc2\nnone").unwrap(), false).is_err());
+    }
+}
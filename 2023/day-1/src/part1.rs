@@ -0,0 +1,13 @@
+use aoc_common::answer::Answer;
+
+use crate::{calibration_sum, parse, Input};
+
+#[tracing::instrument(skip_all)]
+pub fn solve(lines: &Input) -> miette::Result<Answer> {
+    Ok(calibration_sum(lines, false)?.into())
+}
+
+#[tracing::instrument(skip_all)]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    solve(&parse(input)?)
+}
@@ -1,4 +1,4 @@
-use day_1::part1::process;
+use aoc2024_day01::part1::process;
 use miette::Context;
 
 #[tracing::instrument]
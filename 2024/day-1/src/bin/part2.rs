@@ -1,4 +1,4 @@
-use day_1::part2::process;
+use aoc2024_day01::part2::process;
 use miette::Context;
 
 #[tracing::instrument]
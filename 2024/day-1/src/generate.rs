@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use aoc_common::puzzle::Puzzle;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// `size` lines of two five-digit location ids. About half of the right-hand ids repeat a
+/// left-hand one, so the similarity score is not always zero.
+pub fn puzzle(size: usize, seed: u64) -> Puzzle {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let left: Vec<u32> = (0..size).map(|_| rng.gen_range(10_000..100_000)).collect();
+    let right: Vec<u32> = (0..size)
+        .map(|_| {
+            if rng.gen_bool(0.5) {
+                left[rng.gen_range(0..size)]
+            } else {
+                rng.gen_range(10_000..100_000)
+            }
+        })
+        .collect();
+    let input = left
+        .iter()
+        .zip(&right)
+        .map(|(a, b)| format!("{a}   {b}\n"))
+        .collect();
+
+    let mut counts: HashMap<u32, u64> = HashMap::new();
+    for &id in &right {
+        *counts.entry(id).or_default() += 1;
+    }
+    let similarity: u64 = left
+        .iter()
+        .map(|&id| id as u64 * counts.get(&id).copied().unwrap_or_default())
+        .sum();
+    let (mut left, mut right) = (left, right);
+    left.sort_unstable();
+    right.sort_unstable();
+    let distance: u64 = left
+        .iter()
+        .zip(&right)
+        .map(|(a, b)| a.abs_diff(*b) as u64)
+        .sum();
+
+    Puzzle::new(input)
+        .with_part1(distance)
+        .with_part2(similarity)
+}
@@ -1,2 +1,143 @@
+use std::{collections::HashMap, str::FromStr};
+
+pub mod generate;
 pub mod part1;
 pub mod part2;
+
+#[cfg(test)]
+aoc_common::example_tests!();
+
+/// The two columns of location ids from the puzzle input.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LocationLists {
+    pub left: Vec<u32>,
+    pub right: Vec<u32>,
+}
+
+pub type Input = LocationLists;
+
+#[tracing::instrument(skip(input), fields(bytes = input.len(), lines = input.lines().count()))]
+pub fn parse(input: &str) -> miette::Result<Input> {
+    input.parse()
+}
+
+impl FromStr for LocationLists {
+    type Err = miette::Report;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut lists = LocationLists::default();
+        for (index, line) in input.lines().enumerate() {
+            // Skip blank lines, such as a trailing newline.
+            if line.trim().is_empty() {
+                continue;
+            }
+            let mut ids = line.split_whitespace().map(|id| {
+                id.parse::<u32>().map_err(|e| {
+                    miette::miette!("line {}: invalid location id {id:?}: {e}", index + 1)
+                })
+            });
+            match (ids.next(), ids.next(), ids.next()) {
+                (Some(left), Some(right), None) => {
+                    lists.left.push(left?);
+                    lists.right.push(right?);
+                }
+                _ => {
+                    return Err(miette::miette!(
+                        "line {}: expected two location ids, found {line:?}",
+                        index + 1
+                    ))
+                }
+            }
+        }
+        Ok(lists)
+    }
+}
+
+impl LocationLists {
+    /// Sum of the distances between the lists once both are sorted.
+    pub fn total_distance(&self) -> u64 {
+        let mut left = self.left.clone();
+        let mut right = self.right.clone();
+        left.sort_unstable();
+        right.sort_unstable();
+        left.iter()
+            .zip(right.iter())
+            .map(|(a, b)| a.abs_diff(*b) as u64)
+            .sum()
+    }
+
+    /// Sum of each left id multiplied by the number of times it appears in the right list.
+    pub fn similarity_score(&self) -> u64 {
+        let counts = self
+            .right
+            .iter()
+            .fold(HashMap::<u32, u64>::new(), |mut counts, &id| {
+                *counts.entry(id).or_default() += 1;
+                counts
+            });
+        self.left
+            .iter()
+            .map(|id| *id as u64 * counts.get(id).copied().unwrap_or_default())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::strategy::lines;
+    use proptest::prelude::*;
+
+    /// Small ids, so the lists share plenty of them.
+    fn location_lists() -> impl Strategy<Value = String> {
+        lines(
+            (0..50u32, 0..50u32).prop_map(|(left, right)| format!("{left}   {right}")),
+            1..40,
+        )
+    }
+
+    /// Pairs up the smallest remaining ids one at a time, as the puzzle describes it.
+    fn naive_distance(lists: &LocationLists) -> u64 {
+        let (mut left, mut right) = (lists.left.clone(), lists.right.clone());
+        let mut total = 0;
+        while let (Some(a), Some(b)) = (left.iter().min().copied(), right.iter().min().copied()) {
+            left.remove(left.iter().position(|&id| id == a).unwrap());
+            right.remove(right.iter().position(|&id| id == b).unwrap());
+            total += a.abs_diff(b) as u64;
+        }
+        total
+    }
+
+    fn naive_similarity(lists: &LocationLists) -> u64 {
+        lists
+            .left
+            .iter()
+            .map(|&a| a as u64 * lists.right.iter().filter(|&&b| a == b).count() as u64)
+            .sum()
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_naive(input in location_lists()) {
+            let lists: LocationLists = input.parse().unwrap();
+            prop_assert_eq!(naive_distance(&lists), lists.total_distance());
+            prop_assert_eq!(naive_similarity(&lists), lists.similarity_score());
+        }
+    }
+
+    #[test]
+    fn test_parse_any_whitespace() -> miette::Result<()> {
+        let lists: LocationLists = "3   4\n4\t3\r\n 2 5 \n\n".parse()?;
+        assert_eq!(vec![3, 4, 2], lists.left);
+        assert_eq!(vec![4, 3, 5], lists.right);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_malformed_line() {
+        let err = "3   4\n4   3   1".parse::<LocationLists>().unwrap_err();
+        assert!(err.to_string().starts_with("line 2:"));
+        let err = "3   4\nfour   3".parse::<LocationLists>().unwrap_err();
+        assert!(err.to_string().starts_with("line 2:"));
+    }
+}
@@ -0,0 +1,33 @@
+use std::hint::black_box;
+
+use aoc_common::answer::Answer;
+use aoc2024_day10::Input;
+use iai_callgrind::{library_benchmark, library_benchmark_group, main};
+
+// Counts instructions and cache accesses under Callgrind instead of timing, so results
+// stay comparable on noisy machines. Needs valgrind and `iai-callgrind-runner` on the
+// PATH. The parts start from input parsed outside the measurement, like the divan benches.
+fn parsed() -> Input {
+    aoc2024_day10::parse(include_str!("../input.txt")).unwrap()
+}
+
+#[library_benchmark]
+fn parse() -> Input {
+    black_box(aoc2024_day10::parse(black_box(include_str!("../input.txt"))).unwrap())
+}
+
+#[library_benchmark]
+#[bench::puzzle(parsed())]
+fn part1(input: Input) -> Answer {
+    black_box(aoc2024_day10::part1::solve(black_box(&input)).unwrap())
+}
+
+#[library_benchmark]
+#[bench::puzzle(parsed())]
+fn part2(input: Input) -> Answer {
+    black_box(aoc2024_day10::part2::solve(black_box(&input)).unwrap())
+}
+
+library_benchmark_group!(name = day; benchmarks = parse, part1, part2);
+
+main!(library_benchmark_groups = day);
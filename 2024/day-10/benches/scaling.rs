@@ -0,0 +1,26 @@
+use aoc2024_day10::*;
+
+/// Sizes passed to `generate::puzzle`, doubling so the growth fit has a wide spread.
+const SIZES: &[usize] = &[2_500, 5_000, 10_000, 20_000, 40_000];
+
+fn main() {
+    // Run registered benchmarks, then fit how each part grows with the input.
+    divan::main();
+    aoc_common::scaling::report(
+        SIZES,
+        |size| generate::puzzle(size, 0).input,
+        &[("part1", part1::process), ("part2", part2::process)],
+    );
+}
+
+#[divan::bench(args = SIZES)]
+fn part1(bencher: divan::Bencher, size: usize) {
+    let input: &'static str = generate::puzzle(size, 0).input.leak();
+    bencher.bench(|| part1::process(divan::black_box(input)).unwrap());
+}
+
+#[divan::bench(args = SIZES)]
+fn part2(bencher: divan::Bencher, size: usize) {
+    let input: &'static str = generate::puzzle(size, 0).input.leak();
+    bencher.bench(|| part2::process(divan::black_box(input)).unwrap());
+}
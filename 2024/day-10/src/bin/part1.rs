@@ -1,4 +1,4 @@
-use day_10::part1::process;
+use aoc2024_day10::part1::process;
 use miette::Context;
 
 #[tracing::instrument]
@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+
+use aoc_common::puzzle::Puzzle;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const MOVES: [(i32, i32); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+/// Walks every trail up from `(x, y)`, collecting the peaks it ends at and counting trails.
+fn hike(heights: &[Vec<u32>], (x, y): (i32, i32), peaks: &mut HashSet<(i32, i32)>) -> u64 {
+    let height = heights[y as usize][x as usize];
+    if height == 9 {
+        peaks.insert((x, y));
+        return 1;
+    }
+    MOVES
+        .iter()
+        .map(|(dx, dy)| (x + dx, y + dy))
+        .filter(|&(nx, ny)| {
+            let row = usize::try_from(ny).ok().and_then(|ny| heights.get(ny));
+            let next = row.and_then(|row| row.get(usize::try_from(nx).ok()?));
+            next == Some(&(height + 1))
+        })
+        .map(|next| hike(heights, next, peaks))
+        .sum()
+}
+
+/// A square topographic map of about `size` cells. Each height is one off the height
+/// above or to its left, which keeps slopes gentle enough for plenty of trails.
+pub fn puzzle(size: usize, seed: u64) -> Puzzle {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let side = ((size as f64).sqrt().ceil() as usize).max(1);
+    let mut heights: Vec<Vec<u32>> = Vec::with_capacity(side);
+    for y in 0..side {
+        let mut row: Vec<u32> = Vec::with_capacity(side);
+        for x in 0..side {
+            let base = match (x, y) {
+                (0, 0) => rng.gen_range(0..=9),
+                (0, _) => heights[y - 1][x],
+                (_, 0) => row[x - 1],
+                _ if rng.gen_bool(0.5) => heights[y - 1][x],
+                _ => row[x - 1],
+            };
+            let height = match rng.gen_range(0..3) {
+                0 => base.saturating_sub(1),
+                1 => (base + 1).min(9),
+                _ => base,
+            };
+            row.push(height);
+        }
+        heights.push(row);
+    }
+
+    let (mut score, mut rating) = (0, 0);
+    for (y, row) in heights.iter().enumerate() {
+        for (x, &height) in row.iter().enumerate() {
+            if height == 0 {
+                let mut peaks = HashSet::new();
+                rating += hike(&heights, (x as i32, y as i32), &mut peaks);
+                score += peaks.len();
+            }
+        }
+    }
+    let input = heights
+        .iter()
+        .map(|row| {
+            let mut line: String = row
+                .iter()
+                .map(|&h| char::from_digit(h, 10).unwrap())
+                .collect();
+            line.push('\n');
+            line
+        })
+        .collect();
+    Puzzle::new(input).with_part1(score).with_part2(rating)
+}
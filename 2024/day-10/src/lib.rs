@@ -1,2 +1,217 @@
+use std::{collections::HashMap, ops::RangeInclusive};
+
+use glam::IVec2;
+
+pub mod generate;
 pub mod part1;
 pub mod part2;
+
+#[cfg(test)]
+aoc_common::example_tests!();
+
+/// Which heights a trail runs between and how it may move.
+#[derive(Debug, Clone)]
+pub struct TrailRules {
+    /// Trails start at the lowest height and end at the highest.
+    pub heights: RangeInclusive<u32>,
+    /// Height gained by every move.
+    pub step: u32,
+    pub moves: Vec<IVec2>,
+}
+
+impl Default for TrailRules {
+    fn default() -> Self {
+        Self {
+            heights: 0..=9,
+            step: 1,
+            moves: vec![IVec2::NEG_Y, IVec2::Y, IVec2::X, IVec2::NEG_X],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trailhead {
+    pub position: IVec2,
+    /// Number of distinct peaks reachable from the trailhead.
+    pub score: usize,
+    /// Number of distinct trails from the trailhead to any peak.
+    pub rating: u64,
+}
+
+/// Reachable peaks for a single cell, one bit per peak.
+#[derive(Debug, Clone, Default)]
+struct PeakSet(Vec<u64>);
+
+impl PeakSet {
+    fn single(peak: usize, peaks: usize) -> Self {
+        let mut words = vec![0; peaks.div_ceil(64)];
+        words[peak / 64] |= 1 << (peak % 64);
+        PeakSet(words)
+    }
+
+    fn union_with(&mut self, other: &PeakSet) {
+        if self.0.len() < other.0.len() {
+            self.0.resize(other.0.len(), 0);
+        }
+        self.0
+            .iter_mut()
+            .zip(other.0.iter())
+            .for_each(|(a, b)| *a |= b);
+    }
+
+    fn len(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+}
+
+pub type Input = TopoMap;
+
+#[tracing::instrument(skip(input), fields(bytes = input.len(), lines = input.lines().count()))]
+pub fn parse(input: &str) -> miette::Result<Input> {
+    TopoMap::parse(input)
+}
+
+#[derive(Debug, Clone)]
+pub struct TopoMap {
+    size: IVec2,
+    /// Row-major heights, `None` for impassable cells.
+    heights: Vec<Option<u32>>,
+}
+
+impl TopoMap {
+    /// Parses a grid of digits, where `.` marks an impassable cell.
+    pub fn parse(input: &str) -> miette::Result<Self> {
+        let mut heights = vec![];
+        let mut size = IVec2::ZERO;
+        for (y, line) in input.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                heights.push(match c {
+                    '.' => None,
+                    c => Some(
+                        c.to_digit(10)
+                            .ok_or_else(|| miette::miette!("invalid height {c:?} at ({x}, {y})"))?,
+                    ),
+                });
+            }
+            if y == 0 {
+                size.x = line.len() as i32;
+            } else if line.len() as i32 != size.x {
+                return Err(miette::miette!("line {} has a different width", y + 1));
+            }
+            size.y += 1;
+        }
+        Ok(TopoMap { size, heights })
+    }
+
+    fn index(&self, pos: IVec2) -> Option<usize> {
+        (pos.cmpge(IVec2::ZERO).all() && pos.cmplt(self.size).all())
+            .then(|| (pos.y * self.size.x + pos.x) as usize)
+    }
+
+    fn position(&self, index: usize) -> IVec2 {
+        IVec2::new(index as i32 % self.size.x, index as i32 / self.size.x)
+    }
+
+    /// Scores and rates every trailhead in a single pass from the peaks down. A step of
+    /// zero is rejected, since trails would never climb.
+    pub fn trailheads(&self, rules: &TrailRules) -> miette::Result<Vec<Trailhead>> {
+        let (&lowest, &highest) = (rules.heights.start(), rules.heights.end());
+        let step = rules.step;
+        if step == 0 {
+            return Err(miette::miette!("trail step must be at least 1"));
+        }
+        if lowest > highest || (highest - lowest) % step != 0 {
+            return Ok(vec![]);
+        }
+
+        let mut by_height: HashMap<u32, Vec<usize>> = HashMap::new();
+        for (index, height) in self.heights.iter().enumerate() {
+            if let Some(height) = height {
+                by_height.entry(*height).or_default().push(index);
+            }
+        }
+
+        let mut reachable = vec![PeakSet::default(); self.heights.len()];
+        let mut paths = vec![0u64; self.heights.len()];
+        let peaks = by_height.get(&highest).cloned().unwrap_or_default();
+        for (peak, &index) in peaks.iter().enumerate() {
+            reachable[index] = PeakSet::single(peak, peaks.len());
+            paths[index] = 1;
+        }
+
+        let mut height = highest;
+        while height > lowest {
+            height -= step;
+            for &index in by_height.get(&height).into_iter().flatten() {
+                let pos = self.position(index);
+                let mut reach = PeakSet::default();
+                for next in rules.moves.iter().filter_map(|mv| self.index(pos + *mv)) {
+                    if self.heights[next] == Some(height + step) {
+                        reach.union_with(&reachable[next]);
+                        paths[index] += paths[next];
+                    }
+                }
+                reachable[index] = reach;
+            }
+        }
+
+        Ok(by_height
+            .get(&lowest)
+            .into_iter()
+            .flatten()
+            .map(|&index| Trailhead {
+                position: self.position(index),
+                score: reachable[index].len(),
+                rating: paths[index],
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_impassable() -> miette::Result<()> {
+        let map = TopoMap::parse(
+            "..90..9
+...1.98
+...2..7
+6543456
+765.987
+876....
+987....",
+        )?;
+        let trailheads = map.trailheads(&TrailRules::default())?;
+        assert_eq!(1, trailheads.len());
+        assert_eq!(4, trailheads[0].score);
+        assert_eq!(13, trailheads[0].rating);
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_rules() -> miette::Result<()> {
+        let map = TopoMap::parse("024\n2.6\n468")?;
+        let rules = TrailRules {
+            heights: 0..=8,
+            step: 2,
+            moves: TrailRules::default().moves,
+        };
+        let trailheads = map.trailheads(&rules)?;
+        assert_eq!(1, trailheads[0].score);
+        assert_eq!(2, trailheads[0].rating);
+        let diagonal = TrailRules {
+            heights: 0..=4,
+            moves: vec![IVec2::ONE],
+            ..rules
+        };
+        assert_eq!(0, map.trailheads(&diagonal)?[0].score);
+        let flat = TrailRules {
+            step: 0,
+            ..TrailRules::default()
+        };
+        assert!(map.trailheads(&flat).is_err());
+        Ok(())
+    }
+}
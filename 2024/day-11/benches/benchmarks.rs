@@ -1,22 +1,36 @@
-use day_11::*;
+use aoc2024_day11::*;
+
+// Counts allocations with `--features alloc`, the same way the runner does.
+#[cfg(feature = "alloc")]
+#[global_allocator]
+static ALLOC: aoc_common::alloc::CountingAlloc = aoc_common::alloc::CountingAlloc::new();
 
 fn main() {
+    #[cfg(feature = "alloc")]
+    {
+        let input = include_str!("../input.txt");
+        println!("parse: {}", ALLOC.measure(|| aoc2024_day11::parse(input)));
+        let input = aoc2024_day11::parse(input).unwrap();
+        println!("part1: {}", ALLOC.measure(|| part1::solve(&input)));
+        println!("part2: {}", ALLOC.measure(|| part2::solve(&input)));
+    }
     // Run registered benchmarks.
     divan::main();
 }
 
 #[divan::bench]
-fn part1() {
-    part1::process(divan::black_box(include_str!(
-        "../input.txt",
-    )))
-    .unwrap();
+fn parse() {
+    aoc2024_day11::parse(divan::black_box(include_str!("../input.txt",))).unwrap();
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!(
-        "../input.txt",
-    )))
-    .unwrap();
-}
\ No newline at end of file
+fn part1(bencher: divan::Bencher) {
+    let input = aoc2024_day11::parse(include_str!("../input.txt",)).unwrap();
+    bencher.bench(|| part1::solve(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part2(bencher: divan::Bencher) {
+    let input = aoc2024_day11::parse(include_str!("../input.txt",)).unwrap();
+    bencher.bench(|| part2::solve(divan::black_box(&input)).unwrap());
+}
@@ -1,4 +1,4 @@
-use day_11::part2::process;
+use aoc2024_day11::part2::process;
 use miette::Context;
 
 #[tracing::instrument]
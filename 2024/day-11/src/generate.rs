@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use aoc_common::puzzle::Puzzle;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Stones after `blinks`, tracking how many of each engraving there are.
+fn count(stones: &[u64], blinks: usize) -> u64 {
+    let mut counts: HashMap<u64, u64> = HashMap::new();
+    for &stone in stones {
+        *counts.entry(stone).or_default() += 1;
+    }
+    for _ in 0..blinks {
+        let mut next: HashMap<u64, u64> = HashMap::new();
+        for (stone, n) in counts {
+            let digits = stone.checked_ilog10().map_or(1, |log| log + 1);
+            let split = 10u64.pow(digits / 2);
+            let stones = match stone {
+                0 => vec![1],
+                _ if digits % 2 == 0 => vec![stone / split, stone % split],
+                _ => vec![stone * 2024],
+            };
+            for stone in stones {
+                *next.entry(stone).or_default() += n;
+            }
+        }
+        counts = next;
+    }
+    counts.values().sum()
+}
+
+/// `size` stones engraved with numbers below 10 000, a few of them 0. Part 2 blinks 25
+/// times, the same as part 1, until it is extended to the full 75.
+pub fn puzzle(size: usize, seed: u64) -> Puzzle {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let stones: Vec<u64> = (0..size)
+        .map(|_| {
+            if rng.gen_bool(0.1) {
+                0
+            } else {
+                rng.gen_range(1..10_000)
+            }
+        })
+        .collect();
+    let line: Vec<String> = stones.iter().map(u64::to_string).collect();
+    Puzzle::new(line.join(" ") + "\n")
+        .with_part1(count(&stones, 25))
+        .with_part2(count(&stones, 25))
+}
@@ -1,2 +1,41 @@
+pub mod generate;
 pub mod part1;
 pub mod part2;
+
+#[cfg(test)]
+aoc_common::example_tests!();
+
+/// The numbers engraved on the stones, in order.
+pub type Input = Vec<u64>;
+
+#[tracing::instrument(skip(input), fields(bytes = input.len(), lines = input.lines().count()))]
+pub fn parse(input: &str) -> miette::Result<Input> {
+    input
+        .split_ascii_whitespace()
+        .map(|num| {
+            num.parse::<u64>()
+                .map_err(|e| miette::miette!("invalid stone {num:?}: {e}"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use proptest::{collection::vec, prelude::*};
+
+    proptest! {
+        // Part 1 grows the stones one by one, so keep the lists short.
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        /// Both parts blink 25 times: part 1 by growing a `Vec` and part 2 by counting stones.
+        #[test]
+        fn test_counting_matches_growth(stones in vec(0..100_000u64, 1..3)) {
+            let input = stones.iter().join(" ");
+            prop_assert_eq!(
+                crate::part1::process(&input).unwrap(),
+                crate::part2::process(&input).unwrap()
+            );
+        }
+    }
+}
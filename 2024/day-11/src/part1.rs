@@ -1,10 +1,10 @@
-#[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
-    let mut nums: Vec<_> = input
-        .split_whitespace()
-        .map(|s| s.parse::<usize>().unwrap())
-        .collect();
+use aoc_common::answer::Answer;
 
+use crate::{parse, Input};
+
+#[tracing::instrument(skip_all)]
+pub fn solve(stones: &Input) -> miette::Result<Answer> {
+    let mut nums = stones.clone();
     (0..25).for_each(|_| {
         let mut i = 0;
         while i < nums.len() {
@@ -13,10 +13,10 @@ pub fn process(input: &str) -> miette::Result<String> {
                 nums[i] = 1;
             } else {
                 let num = num.to_string();
-                if num.len() % 2 == 0 {
+                if num.len().is_multiple_of(2) {
                     let num_split = num.split_at(num.len() / 2);
-                    nums[i] = num_split.0.parse::<usize>().unwrap();
-                    nums.insert(i + 1, num_split.1.parse::<usize>().unwrap());
+                    nums[i] = num_split.0.parse::<u64>().unwrap();
+                    nums.insert(i + 1, num_split.1.parse::<u64>().unwrap());
                     i += 1;
                 } else {
                     nums[i] *= 2024;
@@ -25,17 +25,10 @@ pub fn process(input: &str) -> miette::Result<String> {
             i += 1;
         }
     });
-    Ok(nums.len().to_string())
+    Ok(nums.len().into())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "125 17";
-        assert_eq!("55312", process(input)?);
-        Ok(())
-    }
+#[tracing::instrument(skip_all)]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    solve(&parse(input)?)
 }
@@ -1,14 +1,12 @@
+use aoc_common::answer::Answer;
 use num_traits::Euclid;
 use std::collections::HashMap;
 
-#[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
-    let nums = input
-        .split_ascii_whitespace()
-        .map(|num| num.parse::<u64>().unwrap())
-        .collect::<Vec<u64>>();
+use crate::{parse, Input};
 
-    let mut cache: HashMap<u64, u64> = nums.iter().fold(HashMap::default(), |mut cache, &num| {
+#[tracing::instrument(skip_all)]
+pub fn solve(stones: &Input) -> miette::Result<Answer> {
+    let mut cache: HashMap<u64, u64> = stones.iter().fold(HashMap::default(), |mut cache, &num| {
         update_cache(&mut cache, num, 1);
         cache
     });
@@ -36,7 +34,12 @@ pub fn process(input: &str) -> miette::Result<String> {
         cache = cache_i;
     }
 
-    Ok(cache.values().sum::<u64>().to_string())
+    Ok(cache.values().sum::<u64>().into())
+}
+
+#[tracing::instrument(skip_all)]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    solve(&parse(input)?)
 }
 
 fn update_cache(cache: &mut HashMap<u64, u64>, num: u64, count: u64) {
@@ -47,15 +50,3 @@ fn update_cache(cache: &mut HashMap<u64, u64>, num: u64, count: u64) {
         })
         .or_insert(count);
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "125 17";
-        assert_eq!("55312", process(input)?);
-        Ok(())
-    }
-}
@@ -1,4 +1,4 @@
-use day_12::part1::process;
+use aoc2024_day12::part1::process;
 use miette::Context;
 
 #[tracing::instrument]
@@ -1,4 +1,4 @@
-use day_12::part2::process;
+use aoc2024_day12::part2::process;
 use miette::Context;
 
 #[tracing::instrument]
@@ -0,0 +1,73 @@
+use aoc_common::puzzle::Puzzle;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Area, perimeter and number of sides of every region, found by flood fill.
+fn measure(garden: &[Vec<u8>]) -> Vec<(usize, usize, usize)> {
+    let side = garden.len() as i32;
+    let plant = |x: i32, y: i32| {
+        ((0..side).contains(&x) && (0..side).contains(&y)).then(|| garden[y as usize][x as usize])
+    };
+    let mut seen = vec![vec![false; side as usize]; side as usize];
+    let mut regions = vec![];
+    for start in (0..side).flat_map(|y| (0..side).map(move |x| (x, y))) {
+        if seen[start.1 as usize][start.0 as usize] {
+            continue;
+        }
+        seen[start.1 as usize][start.0 as usize] = true;
+        let (mut area, mut perimeter, mut corners) = (0, 0, 0);
+        let mut stack = vec![start];
+        while let Some((x, y)) = stack.pop() {
+            let here = plant(x, y);
+            let same = |dx: i32, dy: i32| plant(x + dx, y + dy) == here;
+            area += 1;
+            for (dx, dy) in [(0, -1), (0, 1), (-1, 0), (1, 0)] {
+                if !same(dx, dy) {
+                    perimeter += 1;
+                } else if !seen[(y + dy) as usize][(x + dx) as usize] {
+                    seen[(y + dy) as usize][(x + dx) as usize] = true;
+                    stack.push((x + dx, y + dy));
+                }
+            }
+            for (dx, dy) in [(-1, -1), (1, -1), (-1, 1), (1, 1)] {
+                let (across, down) = (same(dx, 0), same(0, dy));
+                if (!across && !down) || (across && down && !same(dx, dy)) {
+                    corners += 1;
+                }
+            }
+        }
+        regions.push((area, perimeter, corners));
+    }
+    regions
+}
+
+/// A square garden of about `size` plots. Each plot usually copies the plant of the plot
+/// above or to its left, which grows regions of a few dozen plots with ragged edges.
+pub fn puzzle(size: usize, seed: u64) -> Puzzle {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let side = (size as f64).sqrt().ceil().max(1.0) as usize;
+    let mut rows: Vec<Vec<u8>> = Vec::with_capacity(side);
+    for y in 0..side {
+        let mut row: Vec<u8> = Vec::with_capacity(side);
+        for x in 0..side {
+            let plant = match rng.gen_range(0..10) {
+                0..=3 if x > 0 => row[x - 1],
+                4..=7 if y > 0 => rows[y - 1][x],
+                _ => rng.gen_range(b'A'..=b'Z'),
+            };
+            row.push(plant);
+        }
+        rows.push(row);
+    }
+
+    let regions = measure(&rows);
+    let price: usize = regions
+        .iter()
+        .map(|(area, perimeter, _)| area * perimeter)
+        .sum();
+    let discounted: usize = regions.iter().map(|(area, _, sides)| area * sides).sum();
+    let input = rows
+        .into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect();
+    Puzzle::new(input).with_part1(price).with_part2(discounted)
+}
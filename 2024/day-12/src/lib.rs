@@ -1,2 +1,49 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    grid::{Grid, ORTHOGONAL},
+    search::bfs_distances,
+};
+use glam::IVec2;
+
+pub mod generate;
 pub mod part1;
 pub mod part2;
+
+#[cfg(test)]
+aoc_common::example_tests!();
+
+pub type Input = Grid<char>;
+
+#[tracing::instrument(skip(input), fields(bytes = input.len(), lines = input.lines().count()))]
+pub fn parse(input: &str) -> miette::Result<Input> {
+    Grid::parse(input, Ok)
+}
+
+/// Groups the garden into regions of orthogonally connected plots of the same plant.
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn regions(garden: &Grid<char>) -> Vec<Vec<IVec2>> {
+    let same_plant = garden.graph().cost(|from, to| (from == to).then_some(1));
+    let mut seen = HashSet::new();
+    garden
+        .positions()
+        .filter_map(|pos| {
+            if seen.contains(&pos) {
+                return None;
+            }
+            let _region = tracing::trace_span!("region", ?pos).entered();
+            let region: Vec<IVec2> = bfs_distances(&same_plant, pos).into_keys().collect();
+            seen.extend(region.iter().copied());
+            Some(region)
+        })
+        .collect()
+}
+
+/// Number of orthogonal neighbours growing the same plant as `pos`.
+pub fn same_neighbours(garden: &Grid<char>, pos: IVec2) -> usize {
+    let plant = garden.get(pos);
+    garden
+        .neighbours(pos, &ORTHOGONAL)
+        .filter(|next| garden.get(*next) == plant)
+        .count()
+}
@@ -1,4 +1,4 @@
-use day_13::part1::process;
+use aoc2024_day13::part1::process;
 use miette::Context;
 
 #[tracing::instrument]
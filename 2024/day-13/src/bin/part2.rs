@@ -1,4 +1,4 @@
-use day_13::part2::process;
+use aoc2024_day13::part2::process;
 use miette::Context;
 
 #[tracing::instrument]
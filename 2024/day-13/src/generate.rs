@@ -0,0 +1,56 @@
+use aoc_common::puzzle::Puzzle;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Added to both coordinates of every prize in part 2.
+const FAR_OFF: i64 = 10_000_000_000_000;
+
+/// The presses of A and B that land on `prize`, if there is a whole, non-negative number
+/// of each. The buttons are never parallel, so there is at most one answer.
+fn presses(a: (i64, i64), b: (i64, i64), prize: (i64, i64)) -> Option<(i64, i64)> {
+    let det = a.0 * b.1 - a.1 * b.0;
+    let x = prize.0 * b.1 - prize.1 * b.0;
+    let y = a.0 * prize.1 - a.1 * prize.0;
+    (x % det == 0 && y % det == 0 && x / det >= 0 && y / det >= 0).then(|| (x / det, y / det))
+}
+
+/// `size` claw machines with buttons that move 10 to 99 each way and never point the same
+/// way. About two thirds of the prizes are reachable in 1 to 99 presses of each button.
+/// The rest are nudged off so that no number of presses reaches them.
+pub fn puzzle(size: usize, seed: u64) -> Puzzle {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut machines = vec![];
+    while machines.len() < size {
+        let a = (rng.gen_range(10..100), rng.gen_range(10..100));
+        let b = (rng.gen_range(10..100), rng.gen_range(10..100));
+        if a.0 * b.1 == a.1 * b.0 {
+            continue;
+        }
+        let (n, m): (i64, i64) = (rng.gen_range(1..100), rng.gen_range(1..100));
+        let mut prize = (n * a.0 + m * b.0, n * a.1 + m * b.1);
+        if rng.gen_bool(1.0 / 3.0) {
+            prize.0 += rng.gen_range(1..=3);
+            if presses(a, b, prize).is_some() {
+                continue;
+            }
+        }
+        machines.push((a, b, prize));
+    }
+
+    let (mut part1, mut part2) = (0, 0);
+    let mut blocks = vec![];
+    for &(a, b, prize) in &machines {
+        if let Some((n, m)) = presses(a, b, prize) {
+            part1 += 3 * n + m;
+        }
+        if let Some((n, m)) = presses(a, b, (prize.0 + FAR_OFF, prize.1 + FAR_OFF)) {
+            part2 += 3 * n + m;
+        }
+        blocks.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        ));
+    }
+    Puzzle::new(blocks.join("\n"))
+        .with_part1(part1)
+        .with_part2(part2)
+}
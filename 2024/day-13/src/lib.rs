@@ -1,2 +1,63 @@
+use glam::U64Vec2;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, line_ending},
+    multi::{count, separated_list1},
+    sequence::{preceded, separated_pair, terminated, tuple},
+    IResult, Parser,
+};
+
+pub mod generate;
 pub mod part1;
 pub mod part2;
+
+#[cfg(test)]
+aoc_common::example_tests!();
+
+/// A claw machine: how far each button moves the claw, and where the prize is.
+#[derive(Debug, Clone, Copy)]
+pub struct Machine {
+    pub a: U64Vec2,
+    pub b: U64Vec2,
+    pub prize: U64Vec2,
+}
+
+fn parse_button_a(input: &str) -> IResult<&str, U64Vec2> {
+    preceded(
+        tag("Button A: X+"),
+        separated_pair(complete::u64, tag(", Y+"), complete::u64).map(|(x, y)| U64Vec2::new(x, y)),
+    )(input)
+}
+
+fn parse_button_b(input: &str) -> IResult<&str, U64Vec2> {
+    preceded(
+        tag("Button B: X+"),
+        separated_pair(complete::u64, tag(", Y+"), complete::u64).map(|(x, y)| U64Vec2::new(x, y)),
+    )(input)
+}
+
+fn parse_prize(input: &str) -> IResult<&str, U64Vec2> {
+    preceded(
+        tag("Prize: X="),
+        separated_pair(complete::u64, tag(", Y="), complete::u64).map(|(x, y)| U64Vec2::new(x, y)),
+    )(input)
+}
+
+fn parse_machine(input: &str) -> IResult<&str, Machine> {
+    let (input, (a, b, prize)) = tuple((
+        terminated(parse_button_a, line_ending),
+        terminated(parse_button_b, line_ending),
+        parse_prize,
+    ))(input)?;
+
+    Ok((input, Machine { a, b, prize }))
+}
+
+pub type Input = Vec<Machine>;
+
+#[tracing::instrument(skip(input), fields(bytes = input.len(), lines = input.lines().count()))]
+pub fn parse(input: &str) -> miette::Result<Input> {
+    let (_, machines) = separated_list1(count(line_ending, 2), parse_machine)(input)
+        .map_err(|e| miette::miette!("{e}"))?;
+    Ok(machines)
+}
@@ -0,0 +1,22 @@
+use aoc2024_day14::*;
+
+/// Sizes passed to `generate::puzzle`, doubling so the growth fit has a wide spread.
+const SIZES: &[usize] = &[1_000, 2_000, 4_000, 8_000, 16_000];
+
+fn main() {
+    // Run registered benchmarks, then fit how each part grows with the input.
+    // Part 2 waits for every robot to stand on a tile of its own, which random robots may
+    // never do, so only part 1 is timed.
+    divan::main();
+    aoc_common::scaling::report(
+        SIZES,
+        |size| generate::puzzle(size, 0).input,
+        &[("part1", part1::process)],
+    );
+}
+
+#[divan::bench(args = SIZES)]
+fn part1(bencher: divan::Bencher, size: usize) {
+    let input: &'static str = generate::puzzle(size, 0).input.leak();
+    bencher.bench(|| part1::process(divan::black_box(input)).unwrap());
+}
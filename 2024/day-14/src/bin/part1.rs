@@ -1,4 +1,4 @@
-use day_14::part1::process;
+use aoc2024_day14::part1::process;
 use miette::Context;
 
 #[tracing::instrument]
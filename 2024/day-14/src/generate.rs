@@ -0,0 +1,29 @@
+use aoc_common::puzzle::Puzzle;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const WIDTH: i32 = 101;
+const HEIGHT: i32 = 103;
+
+/// `size` robots placed anywhere in the 101 by 103 room, moving up to 100 tiles a second
+/// each way. Spotting the tree is not something a generator can promise, so only part 1
+/// is answered.
+pub fn puzzle(size: usize, seed: u64) -> Puzzle {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut input = String::new();
+    let mut quadrants = [0usize; 4];
+    for _ in 0..size {
+        let (x, y) = (rng.gen_range(0..WIDTH), rng.gen_range(0..HEIGHT));
+        let (dx, dy) = (rng.gen_range(-100..=100), rng.gen_range(-100..=100));
+        input.push_str(&format!("p={x},{y} v={dx},{dy}\n"));
+
+        let (x, y) = (
+            (x + dx * 100).rem_euclid(WIDTH),
+            (y + dy * 100).rem_euclid(HEIGHT),
+        );
+        let (middle_x, middle_y) = (WIDTH / 2, HEIGHT / 2);
+        if x != middle_x && y != middle_y {
+            quadrants[usize::from(x > middle_x) + 2 * usize::from(y > middle_y)] += 1;
+        }
+    }
+    Puzzle::new(input).with_part1(quadrants.iter().product::<usize>())
+}
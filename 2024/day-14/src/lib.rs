@@ -1,2 +1,111 @@
+use glam::IVec2;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, line_ending},
+    combinator::opt,
+    multi::many1,
+    sequence::{preceded, separated_pair, terminated},
+    IResult, Parser,
+};
+
+pub mod generate;
 pub mod part1;
 pub mod part2;
+
+#[cfg(test)]
+aoc_common::example_tests!();
+
+/// A robot's starting position and how far it moves each second.
+#[derive(Debug, Clone, Copy)]
+pub struct Robot {
+    pub position: IVec2,
+    pub velocity: IVec2,
+}
+
+fn parse_position(input: &str) -> IResult<&str, IVec2> {
+    preceded(
+        tag("p="),
+        separated_pair(complete::i32, tag(","), complete::i32).map(|(x, y)| IVec2::new(x, y)),
+    )(input)
+}
+
+fn parse_velocity(input: &str) -> IResult<&str, IVec2> {
+    preceded(
+        tag("v="),
+        separated_pair(complete::i32, tag(","), complete::i32).map(|(x, y)| IVec2::new(x, y)),
+    )(input)
+}
+
+fn parse_robot(input: &str) -> IResult<&str, Robot> {
+    terminated(
+        separated_pair(parse_position, complete::space1, parse_velocity),
+        opt(line_ending),
+    )(input)
+    .map(|(input, (position, velocity))| (input, Robot { position, velocity }))
+}
+
+pub type Input = Vec<Robot>;
+
+#[tracing::instrument(skip(input), fields(bytes = input.len(), lines = input.lines().count()))]
+pub fn parse(input: &str) -> miette::Result<Input> {
+    let (_, robots) = many1(parse_robot)(input).map_err(|e| miette::miette!("{e}"))?;
+    Ok(robots)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::answer::Answer;
+    use aoc_common::strategy::lines;
+    use glam::IVec2;
+    use proptest::prelude::*;
+
+    /// Tests use the example's 11 by 7 room.
+    const SIZE: IVec2 = IVec2::new(11, 7);
+
+    fn robots() -> impl Strategy<Value = String> {
+        let robot = (0..SIZE.x, 0..SIZE.y, -10..=10i32, -10..=10i32)
+            .prop_map(|(x, y, dx, dy)| format!("p={x},{y} v={dx},{dy}"));
+        lines(robot, 1..30)
+    }
+
+    /// Moves every robot one second at a time and multiplies the four quadrant counts.
+    fn naive_safety_factor(input: &str) -> i32 {
+        let mut robots: Vec<(IVec2, IVec2)> = input
+            .lines()
+            .map(|line| {
+                let numbers: Vec<i32> = line
+                    .split(|c: char| !(c.is_ascii_digit() || c == '-'))
+                    .filter_map(|number| number.parse().ok())
+                    .collect();
+                (
+                    IVec2::new(numbers[0], numbers[1]),
+                    IVec2::new(numbers[2], numbers[3]),
+                )
+            })
+            .collect();
+        for _ in 0..100 {
+            for (position, velocity) in robots.iter_mut() {
+                *position = (*position + *velocity).rem_euclid(SIZE);
+            }
+        }
+        let middle = SIZE / 2;
+        let mut quadrants = [0; 4];
+        for (position, _) in robots {
+            if position.x != middle.x && position.y != middle.y {
+                let index = (position.x > middle.x) as usize + 2 * (position.y > middle.y) as usize;
+                quadrants[index] += 1;
+            }
+        }
+        quadrants.iter().product()
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_naive(input in robots()) {
+            prop_assert_eq!(
+                Answer::from(naive_safety_factor(&input)),
+                crate::part1::process(&input).unwrap()
+            );
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use day_15::part1::process;
+use aoc2024_day15::part1::process;
 use miette::Context;
 
 #[tracing::instrument]
@@ -0,0 +1,71 @@
+use aoc_common::puzzle::Puzzle;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// Moves the robot at `robot` one step, pushing any line of boxes in front of it.
+fn step(grid: &mut [Vec<u8>], robot: &mut (usize, usize), (dx, dy): (isize, isize)) {
+    let next = |(x, y): (usize, usize)| (x.wrapping_add_signed(dx), y.wrapping_add_signed(dy));
+    let mut end = next(*robot);
+    while grid[end.1][end.0] == b'O' {
+        end = next(end);
+    }
+    if grid[end.1][end.0] == b'#' {
+        return;
+    }
+    let ahead = next(*robot);
+    grid[end.1][end.0] = b'O';
+    grid[ahead.1][ahead.0] = b'@';
+    grid[robot.1][robot.0] = b'.';
+    *robot = ahead;
+}
+
+/// A walled square warehouse of about `size` tiles, a fifth of them boxes and a few
+/// inside walls, and as many moves as tiles. Only part 1 is answered until part 2 is
+/// solved.
+pub fn puzzle(size: usize, seed: u64) -> Puzzle {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let side = ((size as f64).sqrt().ceil() as usize).max(3);
+    let mut grid: Vec<Vec<u8>> = (0..side)
+        .map(|y| {
+            (0..side)
+                .map(|x| match rng.gen_range(0..20) {
+                    _ if x == 0 || y == 0 || x == side - 1 || y == side - 1 => b'#',
+                    0 => b'#',
+                    1..=4 => b'O',
+                    _ => b'.',
+                })
+                .collect()
+        })
+        .collect();
+    let mut robot = (rng.gen_range(1..side - 1), rng.gen_range(1..side - 1));
+    grid[robot.1][robot.0] = b'@';
+    let map: String = grid
+        .iter()
+        .map(|row| String::from_utf8(row.clone()).unwrap() + "\n")
+        .collect();
+
+    let moves: Vec<u8> = (0..side * side)
+        .map(|_| *b"^v<>".choose(&mut rng).unwrap())
+        .collect();
+    for &mv in &moves {
+        let direction = match mv {
+            b'^' => (0, -1),
+            b'v' => (0, 1),
+            b'<' => (-1, 0),
+            _ => (1, 0),
+        };
+        step(&mut grid, &mut robot, direction);
+    }
+    let gps: usize = grid
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, &c)| (x, y, c)))
+        .filter(|&(_, _, c)| c == b'O')
+        .map(|(x, y, _)| x + 100 * y)
+        .sum();
+
+    let moves: Vec<String> = moves
+        .chunks(70)
+        .map(|line| String::from_utf8(line.to_vec()).unwrap())
+        .collect();
+    Puzzle::new(format!("{map}\n{}\n", moves.join("\n"))).with_part1(gps)
+}
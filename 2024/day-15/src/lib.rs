@@ -1,2 +1,145 @@
+use std::collections::HashMap;
+
+use glam::IVec2;
+use nom::{
+    character::complete::{line_ending, one_of, satisfy},
+    multi::{count, many1, separated_list1},
+    sequence::terminated,
+    IResult, Parser,
+};
+
+pub mod generate;
 pub mod part1;
 pub mod part2;
+
+#[cfg(test)]
+aoc_common::example_tests!();
+
+use nom_locate::{position, LocatedSpan};
+pub type Span<'a> = LocatedSpan<&'a str>;
+
+fn parse_char_pos(input: Span) -> IResult<Span, (IVec2, char)> {
+    let (input, pos) = position(input)?;
+    let x = pos.get_column() as i32 - 1;
+    let y = pos.location_line() as i32 - 1;
+    let (input, c) = satisfy(|c| c != '\n')(input)?;
+    Ok((input, (IVec2::new(x, y), c)))
+}
+
+fn parse_map(input: Span) -> IResult<Span, HashMap<IVec2, char>> {
+    terminated(
+        separated_list1(line_ending, many1(parse_char_pos)).map(|v| {
+            let mut map = HashMap::new();
+            v.iter().flatten().for_each(|(pos, c)| {
+                map.insert(*pos, *c);
+            });
+            map
+        }),
+        count(line_ending, 2),
+    )(input)
+}
+
+fn parse_moves(input: &str) -> IResult<&str, Vec<Vec<char>>> {
+    separated_list1(line_ending, many1(one_of("<^>v")))(input)
+}
+
+/// The warehouse map by position, and the robot's moves with the line breaks dropped.
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    pub map: HashMap<IVec2, char>,
+    pub moves: Vec<char>,
+}
+
+pub type Input = Warehouse;
+
+#[tracing::instrument(skip(input), fields(bytes = input.len(), lines = input.lines().count()))]
+pub fn parse(input: &str) -> miette::Result<Input> {
+    let span = Span::new(input);
+    let (span, map) = parse_map(span).map_err(|e| miette::miette!("{e}"))?;
+    let (_, moves) = parse_moves(span.to_string().as_str()).map_err(|e| miette::miette!("{e}"))?;
+    Ok(Warehouse {
+        map,
+        moves: moves.iter().flatten().cloned().collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::answer::Answer;
+    use itertools::Itertools;
+    use proptest::{collection::vec, prelude::*};
+
+    /// Walled warehouses with a single robot, followed by a line of moves.
+    fn warehouse() -> impl Strategy<Value = String> {
+        (3..10usize, 3..10usize)
+            .prop_flat_map(|(width, height)| {
+                let cell = prop::sample::select(vec!['.', '.', 'O', 'O', '#']);
+                (
+                    vec(vec(cell, width), height),
+                    0..width,
+                    0..height,
+                    vec(prop::sample::select(vec!['<', '^', '>', 'v']), 1..40),
+                )
+            })
+            .prop_map(|(mut rows, x, y, moves)| {
+                rows[y][x] = '@';
+                let width = rows[0].len();
+                let wall = "#".repeat(width + 2);
+                let rows = rows
+                    .iter()
+                    .map(|row| format!("#{}#", row.iter().collect::<String>()));
+                let map = std::iter::once(wall.clone())
+                    .chain(rows)
+                    .chain([wall])
+                    .join("\n");
+                format!("{map}\n\n{}", moves.iter().collect::<String>())
+            })
+    }
+
+    /// Pushes boxes one move at a time on a grid of characters.
+    fn naive_gps_sum(input: &str) -> usize {
+        let (map, moves) = input.split_once("\n\n").unwrap();
+        let mut grid: Vec<Vec<char>> = map.lines().map(|line| line.chars().collect()).collect();
+        let (mut x, mut y) = grid
+            .iter()
+            .enumerate()
+            .find_map(|(y, row)| row.iter().position(|&c| c == '@').map(|x| (x, y)))
+            .unwrap();
+        for mv in moves.chars().filter(|c| !c.is_whitespace()) {
+            let (dx, dy) = match mv {
+                '<' => (-1, 0),
+                '>' => (1, 0),
+                '^' => (0, -1),
+                _ => (0, 1),
+            };
+            let step =
+                |(x, y): (usize, usize)| ((x as i32 + dx) as usize, (y as i32 + dy) as usize);
+            let mut end = step((x, y));
+            while grid[end.1][end.0] == 'O' {
+                end = step(end);
+            }
+            if grid[end.1][end.0] == '.' {
+                let next = step((x, y));
+                grid[end.1][end.0] = 'O';
+                grid[next.1][next.0] = '@';
+                grid[y][x] = '.';
+                (x, y) = next;
+            }
+        }
+        grid.iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .positions(|&c| c == 'O')
+                    .map(move |x| x + 100 * y)
+            })
+            .sum()
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_naive(input in warehouse()) {
+            prop_assert_eq!(Answer::from(naive_gps_sum(&input)), crate::part1::process(&input).unwrap());
+        }
+    }
+}
@@ -1,54 +1,15 @@
-use std::collections::HashMap;
-
+use aoc_common::answer::Answer;
 use glam::IVec2;
-use nom::{
-    character::complete::{line_ending, one_of, satisfy},
-    multi::{count, many1, separated_list1},
-    sequence::terminated,
-    IResult, Parser,
-};
-
-use nom_locate::{position, LocatedSpan};
-pub type Span<'a> = LocatedSpan<&'a str>;
-
-fn parse_char_pos(input: Span) -> IResult<Span, (IVec2, char)> {
-    let (input, pos) = position(input)?;
-    let x = pos.get_column() as i32 - 1;
-    let y = pos.location_line() as i32 - 1;
-    let (input, c) = satisfy(|c| c != '\n')(input)?;
-    Ok((input, (IVec2::new(x, y), c)))
-}
 
-fn parse_map(input: Span) -> IResult<Span, HashMap<IVec2, char>> {
-    terminated(
-        separated_list1(line_ending, many1(parse_char_pos)).map(|v| {
-            let mut map = HashMap::new();
-            v.iter().flatten().for_each(|(pos, c)| {
-                map.insert(*pos, *c);
-            });
-            map
-        }),
-        count(line_ending, 2),
-    )(input)
-}
+use crate::{parse, Input, Warehouse};
 
-fn parse_moves(input: &str) -> IResult<&str, Vec<Vec<char>>> {
-    separated_list1(line_ending, many1(one_of("<^>v")))(input)
-}
-
-fn parse(input: &str) -> miette::Result<(HashMap<IVec2, char>, Vec<char>)> {
-    let span = Span::new(input);
-    let (span, map) = parse_map(span).map_err(|e| miette::miette!("{e}"))?;
-    let (_, moves) = parse_moves(span.to_string().as_str()).map_err(|e| miette::miette!("{e}"))?;
-    Ok((map, moves.iter().flatten().cloned().collect()))
-}
-
-#[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
-    let (mut map, moves) = parse(input)?;
+#[tracing::instrument(skip_all)]
+pub fn solve(warehouse: &Input) -> miette::Result<Answer> {
+    let Warehouse { map, moves } = warehouse;
+    let mut map = map.clone();
     println!("{:?}", moves);
     let mut robot_pos = *map.iter().find(|(_, c)| **c == '@').unwrap().0;
-    for mv in moves {
+    for &mv in moves {
         let next_robot_pos = match mv {
             '^' => IVec2::new(robot_pos.x, robot_pos.y - 1),
             'v' => IVec2::new(robot_pos.x, robot_pos.y + 1),
@@ -97,7 +58,12 @@ pub fn process(input: &str) -> miette::Result<String> {
         .filter(|(_, c)| **c == 'O')
         .map(|(pos, _)| pos.x + pos.y * 100)
         .sum::<i32>();
-    Ok(sum.to_string())
+    Ok(sum.into())
+}
+
+#[tracing::instrument(skip_all)]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    solve(&parse(input)?)
 }
 
 #[cfg(test)]
@@ -127,7 +93,7 @@ vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
 <><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
 ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
 v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
-        assert_eq!("9021", process(input)?);
+        assert_eq!(Answer::from(9021u32), process(input)?);
         Ok(())
     }
 }
@@ -1,4 +1,4 @@
-use day_2::part1::process;
+use aoc2024_day02::part1::process;
 use miette::Context;
 
 #[tracing::instrument]
@@ -1,4 +1,4 @@
-use day_2::part2::process;
+use aoc2024_day02::part2::process;
 use miette::Context;
 
 #[tracing::instrument]
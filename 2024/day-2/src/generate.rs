@@ -0,0 +1,52 @@
+use aoc_common::puzzle::Puzzle;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+fn is_safe(levels: &[i32]) -> bool {
+    let increasing = levels.windows(2).all(|w| (1..=3).contains(&(w[1] - w[0])));
+    let decreasing = levels.windows(2).all(|w| (1..=3).contains(&(w[0] - w[1])));
+    increasing || decreasing
+}
+
+fn is_safe_dampened(levels: &[i32]) -> bool {
+    (0..levels.len()).any(|skip| {
+        let rest: Vec<i32> = [&levels[..skip], &levels[skip + 1..]].concat();
+        is_safe(&rest)
+    })
+}
+
+/// `size` reports of 5 to 8 levels. Most step steadily up or down by 1 to 3, and some
+/// have a level knocked out of line so every safety rule gets exercised.
+pub fn puzzle(size: usize, seed: u64) -> Puzzle {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let reports: Vec<Vec<i32>> = (0..size)
+        .map(|_| {
+            let direction = if rng.gen_bool(0.5) { 1 } else { -1 };
+            let mut level: i32 = rng.gen_range(30..70);
+            let mut levels: Vec<i32> = (0..rng.gen_range(5..=8))
+                .map(|_| {
+                    level += direction * rng.gen_range(1..=3);
+                    level
+                })
+                .collect();
+            if rng.gen_bool(0.4) {
+                let index = rng.gen_range(0..levels.len());
+                levels[index] += rng.gen_range(-5..=5);
+            }
+            levels
+        })
+        .collect();
+    let input = reports
+        .iter()
+        .map(|levels| {
+            let line: Vec<String> = levels.iter().map(i32::to_string).collect();
+            line.join(" ") + "\n"
+        })
+        .collect();
+
+    let safe = reports.iter().filter(|levels| is_safe(levels)).count();
+    let dampened = reports
+        .iter()
+        .filter(|levels| is_safe_dampened(levels))
+        .count();
+    Puzzle::new(input).with_part1(safe).with_part2(dampened)
+}
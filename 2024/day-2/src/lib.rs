@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use itertools::Itertools;
 use nom::{
     character::complete::{self, line_ending, space1},
@@ -5,34 +7,249 @@ use nom::{
     IResult,
 };
 
+pub mod generate;
 pub mod part1;
 pub mod part2;
 
+#[cfg(test)]
+aoc_common::example_tests!();
+
 pub type Report = Vec<i32>;
 
-pub fn parse(input: &str) -> IResult<&str, Vec<Report>> {
+pub type Input = Vec<Report>;
+
+#[tracing::instrument(skip(input), fields(bytes = input.len(), lines = input.lines().count()))]
+pub fn parse(input: &str) -> miette::Result<Input> {
+    let (_, reports) = reports(input).map_err(|e| miette::miette!("{e}"))?;
+    Ok(reports)
+}
+
+fn reports(input: &str) -> IResult<&str, Vec<Report>> {
     separated_list1(line_ending, separated_list1(space1, complete::i32))(input)
 }
 
-pub fn is_safe(report: &Report) -> bool {
-    report
-        .iter()
-        .tuple_windows::<(_, _)>()
-        .try_fold(None, |sign, (a, b)| check_pair_safety(sign, *a, *b))
-        .is_ok()
+/// Outcome of checking a report, with the reason it failed if it is unsafe.
+///
+/// Indices refer to the level that broke the rule, compared with the level before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SafetyVerdict {
+    Safe,
+    /// Safe once the levels at these indices are removed.
+    Dampened {
+        removed: Vec<usize>,
+    },
+    /// The level moves in the opposite direction to the report.
+    WrongSign {
+        index: usize,
+    },
+    /// The level differs from the previous one by a gap outside the allowed range.
+    GapOutOfRange {
+        index: usize,
+        gap: i32,
+    },
+}
+
+impl SafetyVerdict {
+    pub fn is_safe(&self) -> bool {
+        matches!(self, SafetyVerdict::Safe | SafetyVerdict::Dampened { .. })
+    }
+}
+
+/// A rule violation and the levels that could be removed to resolve it.
+struct Violation {
+    verdict: SafetyVerdict,
+    candidates: [Option<usize>; 3],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SafetyRules {
+    /// Allowed absolute difference between adjacent levels.
+    pub gap: RangeInclusive<i32>,
+    /// Number of levels the problem dampener may remove.
+    pub tolerance: usize,
+}
+
+impl Default for SafetyRules {
+    fn default() -> Self {
+        Self {
+            gap: 1..=3,
+            tolerance: 0,
+        }
+    }
+}
+
+impl SafetyRules {
+    pub fn check(&self, report: &[i32]) -> SafetyVerdict {
+        let mut removed = vec![];
+        match self.dampen(report, &mut removed, self.tolerance) {
+            Ok(()) if removed.is_empty() => SafetyVerdict::Safe,
+            Ok(()) => {
+                removed.sort_unstable();
+                SafetyVerdict::Dampened { removed }
+            }
+            Err(verdict) => verdict,
+        }
+    }
+
+    pub fn is_safe(&self, report: &[i32]) -> bool {
+        self.check(report).is_safe()
+    }
+
+    fn dampen(
+        &self,
+        report: &[i32],
+        removed: &mut Vec<usize>,
+        budget: usize,
+    ) -> Result<(), SafetyVerdict> {
+        let Some(violation) = self.first_violation(report, removed) else {
+            return Ok(());
+        };
+        if budget > 0 {
+            for candidate in violation.candidates.into_iter().flatten() {
+                removed.push(candidate);
+                if self.dampen(report, removed, budget - 1).is_ok() {
+                    return Ok(());
+                }
+                removed.pop();
+            }
+        }
+        Err(violation.verdict)
+    }
+
+    fn first_violation(&self, report: &[i32], removed: &[usize]) -> Option<Violation> {
+        let mut sign = 0;
+        let mut before = None;
+        for (a, b) in (0..report.len())
+            .filter(|index| !removed.contains(index))
+            .tuple_windows()
+        {
+            // Removing either level of the pair, or the level before them (which may have
+            // set the wrong direction), are the only removals that can fix the report.
+            let candidates = [Some(b), Some(a), before];
+            let gap = report[b] - report[a];
+            if !self.gap.contains(&gap.abs()) {
+                return Some(Violation {
+                    verdict: SafetyVerdict::GapOutOfRange { index: b, gap },
+                    candidates,
+                });
+            }
+            if sign != 0 && gap.signum() != 0 && gap.signum() != sign {
+                return Some(Violation {
+                    verdict: SafetyVerdict::WrongSign { index: b },
+                    candidates,
+                });
+            }
+            if sign == 0 {
+                sign = gap.signum();
+            }
+            before = Some(a);
+        }
+        None
+    }
 }
 
-fn check_pair_safety(sign: Option<i32>, a: i32, b: i32) -> miette::Result<Option<i32>> {
-    let diff = b - a;
-    let diff_sign = diff.signum();
-    match diff.abs() {
-        1..=3 => {
-            if sign.is_none() || sign.unwrap() == diff_sign {
-                Ok(Some(diff_sign))
-            } else {
-                Err(miette::miette!("wrong sign {diff} ({a}, {b})"))
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answer::Answer;
+    use proptest::{collection::vec, prelude::*};
+
+    /// Reports that mostly drift in one direction, so safe ones are common.
+    fn report() -> impl Strategy<Value = Report> {
+        (0..20i32, vec(-4..=4i32, 0..8)).prop_map(|(start, steps)| {
+            let levels = steps.into_iter().scan(start, |level, step| {
+                *level += step;
+                Some(*level)
+            });
+            std::iter::once(start).chain(levels).collect()
+        })
+    }
+
+    fn naive_is_safe(report: &[i32]) -> bool {
+        let gaps: Vec<i32> = report.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        gaps.iter().all(|gap| (1..=3).contains(gap))
+            || gaps.iter().all(|gap| (-3..=-1).contains(gap))
+    }
+
+    /// Tries every way of removing up to `tolerance` levels.
+    fn naive_is_dampened_safe(report: &[i32], tolerance: usize) -> bool {
+        (0..=tolerance.min(report.len())).any(|removed| {
+            report
+                .iter()
+                .copied()
+                .combinations(report.len() - removed)
+                .any(|kept| naive_is_safe(&kept))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_naive(report in report(), tolerance in 0..3usize) {
+            let rules = SafetyRules { tolerance, ..Default::default() };
+            let verdict = rules.check(&report);
+            prop_assert_eq!(naive_is_dampened_safe(&report, tolerance), verdict.is_safe());
+            if let SafetyVerdict::Dampened { removed } = verdict {
+                let kept: Report = (0..report.len())
+                    .filter(|index| !removed.contains(index))
+                    .map(|index| report[index])
+                    .collect();
+                prop_assert!(naive_is_safe(&kept));
             }
         }
-        _ => Err(miette::miette!("wrong diff {diff} ({a}, {b})")),
+
+        #[test]
+        fn test_process_matches_naive(reports in vec(report(), 1..20)) {
+            let input = reports.iter().map(|report| report.iter().join(" ")).join("\n");
+            let expected = reports.iter().filter(|report| naive_is_dampened_safe(report, 1)).count();
+            prop_assert_eq!(Answer::from(expected), crate::part2::process(input.leak()).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_verdicts() {
+        let rules = SafetyRules::default();
+        assert_eq!(SafetyVerdict::Safe, rules.check(&[7, 6, 4, 2, 1]));
+        assert_eq!(
+            SafetyVerdict::GapOutOfRange { index: 2, gap: 5 },
+            rules.check(&[1, 2, 7, 8, 9])
+        );
+        assert_eq!(
+            SafetyVerdict::WrongSign { index: 2 },
+            rules.check(&[1, 3, 2, 4, 5])
+        );
+    }
+
+    #[test]
+    fn test_dampened() {
+        let rules = SafetyRules {
+            tolerance: 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            SafetyVerdict::Dampened { removed: vec![0] },
+            rules.check(&[5, 1, 2, 3, 4])
+        );
+        assert_eq!(
+            SafetyVerdict::Dampened { removed: vec![0] },
+            rules.check(&[3, 4, 3, 2, 1])
+        );
+        assert_eq!(
+            SafetyVerdict::GapOutOfRange { index: 2, gap: 0 },
+            rules.check(&[8, 6, 6, 6, 1])
+        );
+    }
+
+    #[test]
+    fn test_tolerance_and_gap() {
+        let rules = SafetyRules {
+            gap: 1..=5,
+            tolerance: 2,
+        };
+        assert_eq!(
+            SafetyVerdict::Dampened {
+                removed: vec![2, 3]
+            },
+            rules.check(&[1, 2, 9, 0, 7])
+        );
     }
 }
@@ -1,26 +1,23 @@
-use itertools::Itertools;
+use aoc_common::answer::Answer;
 
-use crate::{check_pair_safety, is_safe, parse};
+use crate::{parse, Input, SafetyRules};
 
-#[tracing::instrument]
-pub fn process(input: &'static str) -> miette::Result<String> {
-    let (_, reports) = parse(input).map_err(|e| miette::miette!(e))?;
+#[tracing::instrument(skip_all)]
+pub fn solve(reports: &Input) -> miette::Result<Answer> {
+    let rules = SafetyRules {
+        tolerance: 1,
+        ..Default::default()
+    };
     let safe_count = reports
         .iter()
-        .filter(|&report| {
-            if is_safe(report) {
-                true
-            } else {
-                report.iter().combinations(report.len() - 1).any(|nums| {
-                    nums.iter()
-                        .tuple_windows::<(_, _)>()
-                        .try_fold(None, |sign, (&a, &b)| check_pair_safety(sign, *a, *b))
-                        .is_ok()
-                })
-            }
-        })
+        .filter(|report| rules.is_safe(report))
         .count();
-    Ok(format!("{}", safe_count))
+    Ok(safe_count.into())
+}
+
+#[tracing::instrument(skip_all)]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    solve(&parse(input)?)
 }
 
 #[cfg(test)]
@@ -32,7 +29,7 @@ mod tests {
         let input = "1 2 7 8 9
 9 7 6 2 1
 1 1 1 1 8";
-        assert_eq!("0", process(input)?);
+        assert_eq!(Answer::from(0u8), process(input)?);
         Ok(())
     }
 
@@ -42,7 +39,7 @@ mod tests {
 1 3 2 4 5
 8 6 4 4 1
 1 3 6 7 9";
-        assert_eq!(input.lines().count(), process(input)?.parse().unwrap());
+        assert_eq!(Answer::from(input.lines().count()), process(input)?);
         Ok(())
     }
 }
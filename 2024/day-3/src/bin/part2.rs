@@ -1,4 +1,4 @@
-use day_3::part2::process;
+use aoc2024_day03::part2::process;
 use miette::Context;
 
 #[tracing::instrument]
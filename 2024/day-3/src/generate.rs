@@ -0,0 +1,53 @@
+use aoc_common::puzzle::Puzzle;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// Fragments that look like instructions but are not, to bury the real ones in. None of
+/// them can combine with a neighbour into a valid instruction.
+const NOISE: &[&str] = &[
+    "mul(4*",
+    "mul[3,7]",
+    "?(12,34)",
+    "mul ( 2 , 4 )",
+    "what()",
+    "from()",
+    "%&",
+    "where(",
+    ")",
+    "don't",
+    "do(1)",
+    "+",
+    " ",
+];
+
+/// Corrupted memory holding `size` instructions: mostly `mul(a,b)` with 1 to 3 digit
+/// operands, some `do()` and `don't()`, and noise between them.
+pub fn puzzle(size: usize, seed: u64) -> Puzzle {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut memory = String::new();
+    let (mut sum, mut enabled_sum, mut enabled) = (0u64, 0u64, true);
+    for _ in 0..size {
+        for _ in 0..rng.gen_range(0..3) {
+            memory.push_str(NOISE.choose(&mut rng).unwrap());
+        }
+        match rng.gen_range(0..10) {
+            0 => {
+                memory.push_str("do()");
+                enabled = true;
+            }
+            1 => {
+                memory.push_str("don't()");
+                enabled = false;
+            }
+            _ => {
+                let (a, b) = (rng.gen_range(1..1000), rng.gen_range(1..1000));
+                memory.push_str(&format!("mul({a},{b})"));
+                sum += a * b;
+                if enabled {
+                    enabled_sum += a * b;
+                }
+            }
+        }
+    }
+    memory.push('\n');
+    Puzzle::new(memory).with_part1(sum).with_part2(enabled_sum)
+}
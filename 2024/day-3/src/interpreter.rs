@@ -0,0 +1,294 @@
+use std::io::BufRead;
+
+use memchr::memchr2;
+use nom::{
+    branch::alt,
+    bytes::streaming::tag,
+    character::streaming as character,
+    combinator::value,
+    sequence::{delimited, separated_pair},
+    IResult, Parser,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
+
+/// An instruction found in corrupted memory, with the byte offset it starts at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Located {
+    pub offset: usize,
+    pub instruction: Instruction,
+}
+
+fn parse_mul(input: &[u8]) -> IResult<&[u8], Instruction> {
+    let (input, _) = tag("mul")(input)?;
+    delimited(
+        tag("("),
+        separated_pair(character::u32, tag(","), character::u32),
+        tag(")"),
+    )
+    .map(|(a, b)| Instruction::Mul(a, b))
+    .parse(input)
+}
+
+/// Parses an instruction at the start of `input`, returning `Incomplete` if the input ends
+/// part way through something that could still become an instruction.
+pub fn parse_instruction(input: &[u8]) -> IResult<&[u8], Instruction> {
+    alt((
+        value(Instruction::Dont, tag("don't()")),
+        value(Instruction::Do, tag("do()")),
+        parse_mul,
+    ))(input)
+}
+
+/// Scans `memory`, which starts at byte `offset` of the whole input, for instructions.
+///
+/// Returns the number of bytes consumed. Unless `last` is set, scanning stops at the first
+/// incomplete candidate so it can be retried once more input is available.
+fn scan_bytes(memory: &[u8], offset: usize, last: bool, found: &mut Vec<Located>) -> usize {
+    let mut pos = 0;
+    // Every instruction starts with `m` or `d`, so jump straight between those bytes.
+    while let Some(start) = memchr2(b'm', b'd', &memory[pos..]) {
+        let start = pos + start;
+        match parse_instruction(&memory[start..]) {
+            Ok((rest, instruction)) => {
+                found.push(Located {
+                    offset: offset + start,
+                    instruction,
+                });
+                pos = memory.len() - rest.len();
+            }
+            Err(nom::Err::Incomplete(_)) if !last => return start,
+            Err(_) => pos = start + 1,
+        }
+    }
+    memory.len()
+}
+
+/// Finds every valid instruction in the corrupted memory, skipping anything else.
+pub fn scan(memory: &str) -> Vec<Located> {
+    let mut found = vec![];
+    scan_bytes(memory.as_bytes(), 0, true, &mut found);
+    found
+}
+
+/// Finds every valid instruction in memory read from `reader`, a chunk at a time.
+pub fn scan_reader(mut reader: impl BufRead) -> std::io::Result<Vec<Located>> {
+    let mut scanner = Scanner::default();
+    let mut found = vec![];
+    loop {
+        let chunk = reader.fill_buf()?;
+        if chunk.is_empty() {
+            break;
+        }
+        let len = chunk.len();
+        scanner.feed(chunk, &mut found);
+        reader.consume(len);
+    }
+    scanner.finish(&mut found);
+    Ok(found)
+}
+
+/// Incremental scanner that carries instructions split across chunk boundaries over to the
+/// next chunk.
+#[derive(Debug, Default)]
+pub struct Scanner {
+    pending: Vec<u8>,
+    offset: usize,
+}
+
+impl Scanner {
+    pub fn feed(&mut self, chunk: &[u8], found: &mut Vec<Located>) {
+        if self.pending.is_empty() {
+            let consumed = scan_bytes(chunk, self.offset, false, found);
+            self.pending.extend_from_slice(&chunk[consumed..]);
+            self.offset += consumed;
+        } else {
+            self.pending.extend_from_slice(chunk);
+            let consumed = scan_bytes(&self.pending, self.offset, false, found);
+            self.pending.drain(..consumed);
+            self.offset += consumed;
+        }
+    }
+
+    pub fn finish(self, found: &mut Vec<Located>) {
+        scan_bytes(&self.pending, self.offset, true, found);
+    }
+}
+
+/// What happened to a `mul` when it was executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Counted {
+        offset: usize,
+        product: u64,
+    },
+    /// Skipped because of the `don't()` at `disabled_at`.
+    Skipped {
+        offset: usize,
+        disabled_at: usize,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct Vm {
+    conditionals: bool,
+    disabled_at: Option<usize>,
+    accumulator: u64,
+    trace: Option<Vec<Step>>,
+}
+
+impl Default for Vm {
+    fn default() -> Self {
+        Self {
+            conditionals: true,
+            disabled_at: None,
+            accumulator: 0,
+            trace: None,
+        }
+    }
+}
+
+impl Vm {
+    /// A VM that treats `do()` and `don't()` as no-ops.
+    pub fn without_conditionals() -> Self {
+        Self {
+            conditionals: false,
+            ..Default::default()
+        }
+    }
+
+    /// Record a [`Step`] for every `mul` executed.
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(vec![]);
+        self
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.disabled_at.is_none()
+    }
+
+    pub fn accumulator(&self) -> u64 {
+        self.accumulator
+    }
+
+    pub fn trace(&self) -> Option<&[Step]> {
+        self.trace.as_deref()
+    }
+
+    pub fn execute(&mut self, located: &Located) {
+        match (located.instruction, self.disabled_at) {
+            (Instruction::Mul(a, b), None) => {
+                let product = a as u64 * b as u64;
+                self.accumulator += product;
+                self.record(Step::Counted {
+                    offset: located.offset,
+                    product,
+                });
+            }
+            (Instruction::Mul(..), Some(disabled_at)) => {
+                self.record(Step::Skipped {
+                    offset: located.offset,
+                    disabled_at,
+                });
+            }
+            (Instruction::Do, _) => {
+                self.disabled_at = None;
+            }
+            (Instruction::Dont, None) if self.conditionals => {
+                self.disabled_at = Some(located.offset);
+            }
+            (Instruction::Dont, _) => {}
+        }
+    }
+
+    pub fn run<'a>(&mut self, program: impl IntoIterator<Item = &'a Located>) -> u64 {
+        program
+            .into_iter()
+            .for_each(|located| self.execute(located));
+        self.accumulator
+    }
+
+    fn record(&mut self, step: Step) {
+        if let Some(trace) = self.trace.as_mut() {
+            trace.push(step);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MEMORY: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn test_scan_offsets() {
+        let found = scan(MEMORY);
+        assert_eq!(
+            vec![
+                (1, Instruction::Mul(2, 4)),
+                (20, Instruction::Dont),
+                (28, Instruction::Mul(5, 5)),
+                (48, Instruction::Mul(11, 8)),
+                (59, Instruction::Do),
+                (64, Instruction::Mul(8, 5)),
+            ],
+            found
+                .iter()
+                .map(|located| (located.offset, located.instruction))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_trailing_garbage() {
+        let found = scan("mul(2,4)xmul(3,7]mul(8,5");
+        assert_eq!(1, found.len());
+        assert_eq!(Instruction::Mul(2, 4), found[0].instruction);
+    }
+
+    #[test]
+    fn test_scan_reader_chunks() -> std::io::Result<()> {
+        let expected = scan(MEMORY);
+        for capacity in 1..MEMORY.len() {
+            let reader = std::io::BufReader::with_capacity(capacity, MEMORY.as_bytes());
+            assert_eq!(expected, scan_reader(reader)?, "capacity {capacity}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut vm = Vm::default().with_trace();
+        assert_eq!(48, vm.run(&scan(MEMORY)));
+        assert_eq!(
+            Some(
+                &[
+                    Step::Counted {
+                        offset: 1,
+                        product: 8
+                    },
+                    Step::Skipped {
+                        offset: 28,
+                        disabled_at: 20
+                    },
+                    Step::Skipped {
+                        offset: 48,
+                        disabled_at: 20
+                    },
+                    Step::Counted {
+                        offset: 64,
+                        product: 40
+                    },
+                ][..]
+            ),
+            vm.trace()
+        );
+    }
+}
@@ -1,2 +1,15 @@
+pub mod generate;
+pub mod interpreter;
 pub mod part1;
 pub mod part2;
+
+#[cfg(test)]
+aoc_common::example_tests!();
+
+/// The instructions found in corrupted memory, in the order they appear.
+pub type Input = Vec<interpreter::Located>;
+
+#[tracing::instrument(skip(input), fields(bytes = input.len(), lines = input.lines().count()))]
+pub fn parse(input: &str) -> miette::Result<Input> {
+    Ok(interpreter::scan(input))
+}
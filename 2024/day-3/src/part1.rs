@@ -1,50 +1,14 @@
-use nom::{
-    bytes::complete::tag,
-    character::complete::{self, anychar},
-    multi::{many1, many_till},
-    sequence::{delimited, separated_pair},
-    IResult, Parser,
-};
+use aoc_common::answer::Answer;
 
-#[derive(Debug)]
-enum Instruction {
-    Mul(u32, u32),
-}
-
-fn parse_mul(input: &str) -> IResult<&str, Instruction> {
-    let (input, _) = tag("mul")(input)?;
-    let (input, pair) = delimited(
-        tag("("),
-        separated_pair(complete::u32, tag(","), complete::u32),
-        tag(")"),
-    )(input)?;
-    Ok((input, Instruction::Mul(pair.0, pair.1)))
-}
+use crate::{interpreter::Vm, parse, Input};
 
-fn parse(input: &str) -> IResult<&str, Vec<Instruction>> {
-    many1(many_till(anychar, parse_mul).map(|(_, instruction)| instruction))(input)
+#[tracing::instrument(skip_all, ret)]
+pub fn solve(program: &Input) -> miette::Result<Answer> {
+    let sum = Vm::without_conditionals().run(program);
+    Ok(sum.into())
 }
 
-#[tracing::instrument(skip(input), ret)]
-pub fn process(input: &'static str) -> miette::Result<String> {
-    let (_, instructions) = parse(input).map_err(|e| miette::miette!(e))?;
-    let sum: u32 = instructions
-        .iter()
-        .map(|instruction| match instruction {
-            Instruction::Mul(a, b) => a * b,
-        })
-        .sum();
-    Ok(format!("{}", sum))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
-        assert_eq!("161", process(input)?);
-        Ok(())
-    }
+#[tracing::instrument(skip_all)]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    solve(&parse(input)?)
 }
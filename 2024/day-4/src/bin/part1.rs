@@ -1,4 +1,4 @@
-use day_4::part1::process;
+use aoc2024_day04::part1::process;
 use miette::Context;
 
 #[tracing::instrument]
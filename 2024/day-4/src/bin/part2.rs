@@ -1,4 +1,4 @@
-use day_4::part2::process;
+use aoc2024_day04::part2::process;
 use miette::Context;
 
 #[tracing::instrument]
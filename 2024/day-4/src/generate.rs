@@ -0,0 +1,89 @@
+use aoc_common::puzzle::Puzzle;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+const DIRECTIONS: [(i32, i32); 8] = [
+    (0, -1),
+    (0, 1),
+    (-1, 0),
+    (1, 0),
+    (-1, -1),
+    (1, -1),
+    (-1, 1),
+    (1, 1),
+];
+
+fn at(grid: &[Vec<u8>], x: i32, y: i32) -> Option<u8> {
+    let row = grid.get(usize::try_from(y).ok()?)?;
+    row.get(usize::try_from(x).ok()?).copied()
+}
+
+fn count_xmas(grid: &[Vec<u8>]) -> usize {
+    let side = grid.len() as i32;
+    (0..side)
+        .flat_map(|y| (0..side).map(move |x| (x, y)))
+        .flat_map(|(x, y)| DIRECTIONS.iter().map(move |&(dx, dy)| (x, y, dx, dy)))
+        .filter(|&(x, y, dx, dy)| {
+            (0..4).all(|i| at(grid, x + dx * i, y + dy * i) == Some(b"XMAS"[i as usize]))
+        })
+        .count()
+}
+
+fn count_x_mas(grid: &[Vec<u8>]) -> usize {
+    let side = grid.len() as i32;
+    let is_mas = |a: Option<u8>, b: Option<u8>| {
+        matches!((a, b), (Some(b'M'), Some(b'S')) | (Some(b'S'), Some(b'M')))
+    };
+    (1..side - 1)
+        .flat_map(|y| (1..side - 1).map(move |x| (x, y)))
+        .filter(|&(x, y)| {
+            at(grid, x, y) == Some(b'A')
+                && is_mas(at(grid, x - 1, y - 1), at(grid, x + 1, y + 1))
+                && is_mas(at(grid, x + 1, y - 1), at(grid, x - 1, y + 1))
+        })
+        .count()
+}
+
+/// A square word search of about `size` letters. The background is random `X`, `M`, `A`
+/// and `S`, with extra `XMAS` words and `X-MAS` crosses planted over it.
+pub fn puzzle(size: usize, seed: u64) -> Puzzle {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let side = ((size as f64).sqrt().ceil() as usize).max(4);
+    let mut grid: Vec<Vec<u8>> = (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| *b"XMAS".choose(&mut rng).unwrap())
+                .collect()
+        })
+        .collect();
+
+    for _ in 0..side * side / 40 {
+        let (dx, dy) = *DIRECTIONS.choose(&mut rng).unwrap();
+        let x = rng.gen_range(0..side as i32);
+        let y = rng.gen_range(0..side as i32);
+        if at(&grid, x + dx * 3, y + dy * 3).is_some() {
+            for (i, letter) in b"XMAS".iter().enumerate() {
+                grid[(y + dy * i as i32) as usize][(x + dx * i as i32) as usize] = *letter;
+            }
+        }
+    }
+    for _ in 0..side * side / 40 {
+        let x = rng.gen_range(1..side - 1);
+        let y = rng.gen_range(1..side - 1);
+        let [a, b, c, d] = *[*b"MSMS", *b"MMSS", *b"SSMM", *b"SMSM"]
+            .choose(&mut rng)
+            .unwrap();
+        grid[y][x] = b'A';
+        grid[y - 1][x - 1] = a;
+        grid[y - 1][x + 1] = b;
+        grid[y + 1][x - 1] = c;
+        grid[y + 1][x + 1] = d;
+    }
+
+    let input = grid
+        .iter()
+        .map(|row| String::from_utf8(row.clone()).unwrap() + "\n")
+        .collect();
+    Puzzle::new(input)
+        .with_part1(count_xmas(&grid))
+        .with_part2(count_x_mas(&grid))
+}
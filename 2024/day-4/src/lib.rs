@@ -1,2 +1,277 @@
+use itertools::Itertools;
+use nom::{
+    character::complete::{self, line_ending},
+    multi::separated_list1,
+    IResult,
+};
+
+pub mod generate;
 pub mod part1;
 pub mod part2;
+
+#[cfg(test)]
+aoc_common::example_tests!();
+
+type Row = Vec<char>;
+pub type Matrix = Vec<Row>;
+
+pub type Input = WordSearch;
+
+#[tracing::instrument(skip(input), fields(bytes = input.len(), lines = input.lines().count()))]
+pub fn parse(input: &str) -> miette::Result<Input> {
+    let (_, matrix) = matrix(input).map_err(|e| miette::miette!("{e}"))?;
+    Ok(WordSearch::new(matrix))
+}
+
+fn matrix(input: &str) -> IResult<&str, Matrix> {
+    separated_list1(line_ending, complete::alpha1)(input).map(|(input, rows)| {
+        (
+            input,
+            rows.into_iter().map(|row| row.chars().collect()).collect(),
+        )
+    })
+}
+
+/// The eight directions a word can run in, each one followed by its opposite.
+pub const DIRECTIONS: [(i32, i32); 8] = [
+    (0, -1),
+    (0, 1),
+    (-1, 0),
+    (1, 0),
+    (-1, -1),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+];
+
+/// A 2D stencil of letters, where `.` in the source matches any letter. Every cell,
+/// including a `.`, has to land on the grid.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pattern {
+    /// `None` for a `.`.
+    cells: Vec<(i32, i32, Option<char>)>,
+}
+
+impl From<&str> for Pattern {
+    fn from(input: &str) -> Self {
+        let cells = input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(move |(x, c)| (x as i32, y as i32, (c != '.').then_some(c)))
+            })
+            .collect();
+        Pattern::normalised(cells)
+    }
+}
+
+impl Pattern {
+    fn normalised(mut cells: Vec<(i32, i32, Option<char>)>) -> Self {
+        let min_x = cells.iter().map(|(x, _, _)| *x).min().unwrap_or_default();
+        let min_y = cells.iter().map(|(_, y, _)| *y).min().unwrap_or_default();
+        cells
+            .iter_mut()
+            .for_each(|(x, y, _)| (*x, *y) = (*x - min_x, *y - min_y));
+        cells.sort_unstable();
+        Pattern { cells }
+    }
+
+    /// Every distinct rotation and reflection of the pattern.
+    pub fn variants(&self) -> Vec<Pattern> {
+        let rotate = |pattern: &Pattern| {
+            Pattern::normalised(pattern.cells.iter().map(|&(x, y, c)| (-y, x, c)).collect())
+        };
+        let reflect = |pattern: &Pattern| {
+            Pattern::normalised(pattern.cells.iter().map(|&(x, y, c)| (-x, y, c)).collect())
+        };
+        [self.clone(), reflect(self)]
+            .into_iter()
+            .flat_map(|pattern| std::iter::successors(Some(pattern), |p| Some(rotate(p))).take(4))
+            .unique()
+            .collect()
+    }
+}
+
+pub struct WordSearch {
+    matrix: Matrix,
+    cols: usize,
+    rows: usize,
+}
+
+impl WordSearch {
+    pub fn new(matrix: Matrix) -> WordSearch {
+        let cols = matrix.first().map(Vec::len).unwrap_or_default();
+        let rows = matrix.len();
+        WordSearch { matrix, cols, rows }
+    }
+
+    fn get(&self, x: i32, y: i32) -> Option<char> {
+        if x < 0 || y < 0 || x >= self.cols as i32 || y >= self.rows as i32 {
+            return None;
+        }
+        self.matrix[y as usize].get(x as usize).copied()
+    }
+
+    fn positions(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        (0..self.rows as i32)
+            .cartesian_product(0..self.cols as i32)
+            .map(|(y, x)| (x, y))
+    }
+
+    /// Counts occurrences of `word` reading in any of the 8 directions. Each run of cells
+    /// counts once, so a palindrome read from either end is one occurrence, and so is a
+    /// single letter.
+    pub fn count_word(&self, word: &str) -> usize {
+        let letters: Vec<char> = word.chars().collect();
+        let Some(&first) = letters.first() else {
+            return 0;
+        };
+        // Every other direction is the opposite of the one before it, so a palindrome only
+        // needs the second of each pair, and a single letter only needs one direction.
+        let (skip, step) = match letters.len() {
+            1 => (0, DIRECTIONS.len()),
+            _ if letters.iter().eq(letters.iter().rev()) => (1, 2),
+            _ => (0, 1),
+        };
+        self.positions()
+            .filter(|&(x, y)| self.get(x, y) == Some(first))
+            .map(|(x, y)| {
+                DIRECTIONS
+                    .iter()
+                    .skip(skip)
+                    .step_by(step)
+                    .filter(|(dx, dy)| {
+                        (1..letters.len() as i32)
+                            .all(|i| self.get(x + dx * i, y + dy * i) == Some(letters[i as usize]))
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Counts placements of `pattern` in any rotation or reflection.
+    pub fn count_pattern(&self, pattern: &Pattern) -> usize {
+        let variants = pattern.variants();
+        self.positions()
+            .map(|(x, y)| {
+                variants
+                    .iter()
+                    .filter(|variant| {
+                        variant.cells.iter().all(|(dx, dy, c)| {
+                            self.get(x + dx, y + dy)
+                                .is_some_and(|letter| c.is_none_or(|c| c == letter))
+                        })
+                    })
+                    .count()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::strategy::grid;
+    use proptest::prelude::*;
+
+    fn word_search(input: &str) -> WordSearch {
+        parse(input).unwrap()
+    }
+
+    /// Grids of only the letters of XMAS, so the word turns up often.
+    fn xmas_grid() -> impl Strategy<Value = String> {
+        grid(1..12, 1..12, prop::sample::select(vec!['X', 'M', 'A', 'S']))
+    }
+
+    /// Counts XMAS read forwards and backwards along every row, column and diagonal.
+    fn naive_count_xmas(input: &str) -> usize {
+        let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+        let (height, width) = (rows.len() as i32, rows[0].len() as i32);
+        let at = |x: i32, y: i32| rows[y as usize][x as usize];
+        let mut lines: Vec<String> = vec![];
+        lines.extend((0..height).map(|y| (0..width).map(|x| at(x, y)).collect()));
+        lines.extend((0..width).map(|x| (0..height).map(|y| at(x, y)).collect()));
+        for start in -height..width {
+            lines.push(
+                (0..height)
+                    .filter(|y| (0..width).contains(&(start + y)))
+                    .map(|y| at(start + y, y))
+                    .collect(),
+            );
+            lines.push(
+                (0..height)
+                    .filter(|y| (0..width).contains(&(start - y + height)))
+                    .map(|y| at(start - y + height, y))
+                    .collect(),
+            );
+        }
+        lines
+            .iter()
+            .map(|line| line.matches("XMAS").count() + line.matches("SAMX").count())
+            .sum()
+    }
+
+    /// Counts 3x3 windows with an `A` in the middle and `MAS` along both diagonals.
+    fn naive_count_x_mas(input: &str) -> usize {
+        let rows: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        let is_mas = |a: u8, b: u8| matches!((a, b), (b'M', b'S') | (b'S', b'M'));
+        (1..rows.len().saturating_sub(1))
+            .flat_map(|y| (1..rows[0].len().saturating_sub(1)).map(move |x| (x, y)))
+            .filter(|&(x, y)| {
+                rows[y][x] == b'A'
+                    && is_mas(rows[y - 1][x - 1], rows[y + 1][x + 1])
+                    && is_mas(rows[y - 1][x + 1], rows[y + 1][x - 1])
+            })
+            .count()
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_naive(input in xmas_grid()) {
+            let search = word_search(&input);
+            prop_assert_eq!(naive_count_xmas(&input), search.count_word("XMAS"));
+            prop_assert_eq!(
+                naive_count_x_mas(&input),
+                search.count_pattern(&Pattern::from("M.S\n.A.\nM.S"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_count_word() {
+        let search = word_search("ABCD\nBXXC\nCXXB\nDCBA");
+        assert_eq!(4, search.count_word("ABCD"));
+        assert_eq!(1, search.count_word("AXXA"));
+        assert_eq!(0, search.count_word(""));
+
+        let search = word_search("ABA\nBAB\nABA");
+        assert_eq!(5, search.count_word("A"));
+        assert_eq!(4, search.count_word("ABA"));
+        assert_eq!(2, search.count_word("BAB"));
+        assert_eq!(12, search.count_word("AB"));
+
+        let search = word_search("AXA\nXBX\nAXA");
+        assert_eq!(2, search.count_word("ABA"));
+        assert_eq!(4, search.count_word("AXA"));
+        let search = word_search("AXX\nXBX\nXXA");
+        assert_eq!(1, search.count_word("ABA"));
+    }
+
+    #[test]
+    fn test_variants() {
+        assert_eq!(4, Pattern::from("M.S\n.A.\nM.S").variants().len());
+        assert_eq!(8, Pattern::from("AB\nC.").variants().len());
+        assert_eq!(1, Pattern::from("A").variants().len());
+    }
+
+    #[test]
+    fn test_count_pattern() {
+        let search = word_search("ABA\nBAB\nABA");
+        assert_eq!(1, search.count_pattern(&Pattern::from("A.A\n.A.\nA.A")));
+        assert_eq!(12, search.count_pattern(&Pattern::from("AB")));
+        assert_eq!(0, word_search("A").count_pattern(&Pattern::from("A.")));
+        assert_eq!(4, search.count_pattern(&Pattern::from("B..")));
+    }
+}
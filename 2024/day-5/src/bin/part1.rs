@@ -1,4 +1,4 @@
-use day_5::part1::process;
+use aoc2024_day05::part1::process;
 use miette::Context;
 
 #[tracing::instrument]
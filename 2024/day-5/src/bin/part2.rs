@@ -1,4 +1,4 @@
-use day_5::part2::process;
+use aoc2024_day05::part2::process;
 use miette::Context;
 
 #[tracing::instrument]
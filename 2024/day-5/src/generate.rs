@@ -0,0 +1,54 @@
+use aoc_common::puzzle::Puzzle;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// 49 two-digit pages in a random total order, with a rule for every pair of them, then
+/// `size` updates of an odd number of pages. About half of the updates are printed in
+/// order and the rest shuffled.
+pub fn puzzle(size: usize, seed: u64) -> Puzzle {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut order: Vec<u32> = (10..100).collect();
+    order.shuffle(&mut rng);
+    order.truncate(49);
+    let rank = |page: &u32| order.iter().position(|p| p == page).unwrap();
+
+    let mut rules: Vec<(u32, u32)> = order
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &before)| order[i + 1..].iter().map(move |&after| (before, after)))
+        .collect();
+    rules.shuffle(&mut rng);
+
+    let mut in_order = 0;
+    let mut reordered = 0;
+    let updates: Vec<Vec<u32>> = (0..size)
+        .map(|_| {
+            let length = rng.gen_range(2..=11) * 2 + 1;
+            let mut update: Vec<u32> = order.choose_multiple(&mut rng, length).copied().collect();
+            if rng.gen_bool(0.5) {
+                update.sort_by_key(rank);
+            }
+            let mut sorted = update.clone();
+            sorted.sort_by_key(rank);
+            if sorted == update {
+                in_order += update[update.len() / 2];
+            } else {
+                reordered += sorted[sorted.len() / 2];
+            }
+            update
+        })
+        .collect();
+
+    let mut input: String = rules
+        .iter()
+        .map(|(before, after)| format!("{before}|{after}\n"))
+        .collect();
+    input.push('\n');
+    for update in updates {
+        let pages: Vec<String> = update.iter().map(u32::to_string).collect();
+        input.push_str(&pages.join(","));
+        input.push('\n');
+    }
+    Puzzle::new(input)
+        .with_part1(in_order)
+        .with_part2(reordered)
+}
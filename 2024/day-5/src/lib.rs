@@ -1,2 +1,234 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+use nom::{
+    character::complete::{self, char, line_ending, multispace0},
+    multi::{many1, separated_list1},
+    sequence::{separated_pair, terminated},
+    IResult,
+};
+
+pub mod generate;
 pub mod part1;
 pub mod part2;
+
+#[cfg(test)]
+aoc_common::example_tests!();
+
+pub type Rule = (u32, u32);
+pub type Update = Vec<u32>;
+
+pub type Input = PrintQueue;
+
+#[derive(Debug, Clone)]
+pub struct PrintQueue {
+    pub rules: RuleSet,
+    pub updates: Vec<Update>,
+}
+
+fn parse_rule(input: &str) -> IResult<&str, Rule> {
+    separated_pair(complete::u32, char('|'), complete::u32)(input)
+}
+
+fn parse_update(input: &str) -> IResult<&str, Update> {
+    separated_list1(char(','), complete::u32)(input)
+}
+
+fn parse_sections(input: &str) -> IResult<&str, (Vec<Rule>, Vec<Update>)> {
+    let (input, rules) = many1(terminated(parse_rule, line_ending))(input)?;
+    let (input, _) = many1(line_ending)(input)?;
+    let (input, updates) =
+        terminated(separated_list1(line_ending, parse_update), multispace0)(input)?;
+    Ok((input, (rules, updates)))
+}
+
+/// Parses the rules and updates, accepting either `\n` or `\r\n` line endings.
+#[tracing::instrument(skip(input), fields(bytes = input.len(), lines = input.lines().count()))]
+pub fn parse(input: &str) -> miette::Result<Input> {
+    let (rest, (rules, updates)) = parse_sections(input).map_err(|e| miette::miette!("{e}"))?;
+    if !rest.is_empty() {
+        return Err(miette::miette!("unexpected input after updates: {rest:?}"));
+    }
+    if let Some((index, update)) = updates
+        .iter()
+        .enumerate()
+        .find(|(_, update)| update.len() % 2 == 0)
+    {
+        return Err(miette::miette!(
+            "update {} has an even number of pages ({}), so it has no middle page",
+            index + 1,
+            update.len()
+        ));
+    }
+    Ok(PrintQueue {
+        rules: RuleSet::new(rules),
+        updates,
+    })
+}
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+pub enum OrderingError {
+    #[error("pages {pages:?} form a cycle")]
+    Cycle { pages: Vec<u32> },
+    #[error("no rule orders page {a} relative to page {b}")]
+    Ambiguous { a: u32, b: u32 },
+    #[error("page {page} appears more than once in the update")]
+    Duplicate { page: u32 },
+}
+
+/// Page-ordering rules as a precedence graph.
+#[derive(Debug, Default, Clone)]
+pub struct RuleSet {
+    after: HashMap<u32, HashSet<u32>>,
+}
+
+impl RuleSet {
+    /// Builds the graph from `(before, after)` rules.
+    pub fn new(rules: impl IntoIterator<Item = Rule>) -> Self {
+        let mut after: HashMap<u32, HashSet<u32>> = HashMap::new();
+        for (a, b) in rules {
+            after.entry(a).or_default().insert(b);
+        }
+        RuleSet { after }
+    }
+
+    /// Whether `a` must be printed before `b`.
+    pub fn precedes(&self, a: u32, b: u32) -> bool {
+        self.after.get(&a).is_some_and(|after| after.contains(&b))
+    }
+
+    /// Whether no rule requires a later page of the update to come before an earlier one.
+    pub fn is_ordered(&self, update: &[u32]) -> bool {
+        update
+            .iter()
+            .enumerate()
+            .all(|(i, &a)| update[i + 1..].iter().all(|&b| !self.precedes(b, a)))
+    }
+
+    /// Topologically sorts the update using only the rules between its own pages. An
+    /// update that repeats a page is rejected, since its sorted form would be shorter.
+    pub fn sort(&self, update: &[u32]) -> Result<Vec<u32>, OrderingError> {
+        let mut pages: HashSet<u32> = HashSet::with_capacity(update.len());
+        if let Some(&page) = update.iter().find(|&&page| !pages.insert(page)) {
+            return Err(OrderingError::Duplicate { page });
+        }
+        let mut before_count: HashMap<u32, usize> = pages
+            .iter()
+            .map(|&page| {
+                let count = pages.iter().filter(|&&b| self.precedes(b, page)).count();
+                (page, count)
+            })
+            .collect();
+
+        let mut sorted = Vec::with_capacity(pages.len());
+        while !before_count.is_empty() {
+            // Sorted, so an ambiguity always names the same two pages.
+            let mut ready = before_count
+                .iter()
+                .filter(|(_, &count)| count == 0)
+                .map(|(&page, _)| page)
+                .k_smallest(2);
+            let page = match (ready.next(), ready.next()) {
+                (Some(page), None) => page,
+                (Some(a), Some(b)) => return Err(OrderingError::Ambiguous { a, b }),
+                (None, _) => {
+                    return Err(OrderingError::Cycle {
+                        pages: self.find_cycle(&before_count),
+                    })
+                }
+            };
+            before_count.remove(&page);
+            for next in self.after.get(&page).into_iter().flatten() {
+                if let Some(count) = before_count.get_mut(next) {
+                    *count -= 1;
+                }
+            }
+            sorted.push(page);
+        }
+        Ok(sorted)
+    }
+
+    /// Every remaining page has a remaining predecessor, so walking predecessors must
+    /// eventually revisit a page.
+    fn find_cycle(&self, remaining: &HashMap<u32, usize>) -> Vec<u32> {
+        let predecessor = |page: u32| {
+            remaining
+                .keys()
+                .copied()
+                .filter(|&b| self.precedes(b, page))
+                .min()
+        };
+        let mut path = vec![];
+        let mut page = remaining.keys().copied().min();
+        while let Some(current) = page {
+            if let Some(start) = path.iter().position(|&p| p == current) {
+                let mut cycle = path.split_off(start);
+                cycle.reverse();
+                if let Some(first) = cycle.iter().position_min() {
+                    cycle.rotate_left(first);
+                }
+                return cycle;
+            }
+            path.push(current);
+            page = predecessor(current);
+        }
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line_endings() -> miette::Result<()> {
+        let unix = parse("1|2\n2|3\n\n1,2,3\n3,2,1\n")?;
+        let windows = parse("1|2\r\n2|3\r\n\r\n1,2,3\r\n3,2,1\r\n")?;
+        assert_eq!(unix.updates, windows.updates);
+        assert_eq!(vec![vec![1, 2, 3], vec![3, 2, 1]], windows.updates);
+        assert!(windows.rules.precedes(2, 3));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_even_update() {
+        let err = parse("1|2\n\n1,2,3\n1,2").unwrap_err();
+        assert!(err.to_string().starts_with("update 2 has an even number"));
+    }
+
+    #[test]
+    fn test_sort() -> miette::Result<()> {
+        let rules = RuleSet::new([(1, 2), (2, 3), (1, 3), (4, 1)]);
+        assert!(rules.is_ordered(&[1, 2, 3]));
+        assert!(!rules.is_ordered(&[1, 3, 2]));
+        assert_eq!(vec![4, 1, 2, 3], rules.sort(&[3, 2, 1, 4])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ambiguous() {
+        let rules = RuleSet::new([(1, 2), (1, 3), (1, 4)]);
+        match rules.sort(&[3, 4, 2, 1]) {
+            Err(OrderingError::Ambiguous { a, b }) => assert_eq!((2, 3), (a, b)),
+            result => panic!("expected ambiguous ordering, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_duplicate() {
+        let rules = RuleSet::new([(1, 2), (2, 3)]);
+        match rules.sort(&[3, 2, 3]) {
+            Err(OrderingError::Duplicate { page }) => assert_eq!(3, page),
+            result => panic!("expected a duplicate page, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_cycle() {
+        let rules = RuleSet::new([(5, 1), (1, 2), (2, 3), (3, 1)]);
+        match rules.sort(&[1, 2, 3, 5]) {
+            Err(OrderingError::Cycle { pages }) => assert_eq!(vec![1, 2, 3], pages),
+            result => panic!("expected cycle, got {result:?}"),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use day_6::part1::process;
+use aoc2024_day06::part1::process;
 use miette::Context;
 
 #[tracing::instrument]
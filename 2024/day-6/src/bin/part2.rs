@@ -1,4 +1,4 @@
-use day_6::part2::process;
+use aoc2024_day06::part2::process;
 use miette::Context;
 
 #[tracing::instrument]
@@ -0,0 +1,54 @@
+use std::collections::HashSet;
+
+use aoc_common::puzzle::Puzzle;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Cells the guard visits before walking off `grid`, or `None` if the patrol loops.
+fn patrol(grid: &[Vec<u8>], start: (i32, i32)) -> Option<usize> {
+    let side = grid.len() as i32;
+    let (mut position, mut direction) = (start, (0, -1));
+    let mut seen = HashSet::from([(position, direction)]);
+    loop {
+        let next = (position.0 + direction.0, position.1 + direction.1);
+        if !(0..side).contains(&next.0) || !(0..side).contains(&next.1) {
+            break;
+        }
+        if grid[next.1 as usize][next.0 as usize] == b'#' {
+            direction = (-direction.1, direction.0);
+        } else {
+            position = next;
+        }
+        if !seen.insert((position, direction)) {
+            return None;
+        }
+    }
+    let cells: HashSet<(i32, i32)> = seen.into_iter().map(|(position, _)| position).collect();
+    Some(cells.len())
+}
+
+/// A square lab of about `size` cells, with roughly one cell in ten an obstruction and the
+/// guard facing up. Maps where the guard would patrol forever are redrawn. Finding the
+/// loop-making obstructions is not cheap, so only part 1 is answered.
+pub fn puzzle(size: usize, seed: u64) -> Puzzle {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let side = ((size as f64).sqrt().ceil() as usize).max(2);
+    loop {
+        let mut grid: Vec<Vec<u8>> = (0..side)
+            .map(|_| {
+                (0..side)
+                    .map(|_| if rng.gen_bool(0.1) { b'#' } else { b'.' })
+                    .collect()
+            })
+            .collect();
+        let start = (rng.gen_range(0..side), rng.gen_range(0..side));
+        grid[start.1][start.0] = b'^';
+        let Some(visited) = patrol(&grid, (start.0 as i32, start.1 as i32)) else {
+            continue;
+        };
+        let input = grid
+            .into_iter()
+            .map(|row| String::from_utf8(row).unwrap() + "\n")
+            .collect();
+        return Puzzle::new(input).with_part1(visited);
+    }
+}
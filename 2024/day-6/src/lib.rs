@@ -1,8 +1,12 @@
 use std::collections::HashSet;
 
+pub mod generate;
 pub mod part1;
 pub mod part2;
 
+#[cfg(test)]
+aoc_common::example_tests!();
+
 #[derive(Debug, Clone, Copy)]
 enum GameStatus {
     Running,
@@ -36,22 +40,24 @@ struct Position {
     y: usize,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
 enum Direction {
+    #[default]
     Up,
     Down,
     Left,
     Right,
 }
 
-impl Default for Direction {
-    fn default() -> Self {
-        Self::Up
-    }
+pub type Input = Game;
+
+#[tracing::instrument(skip(input), fields(bytes = input.len(), lines = input.lines().count()))]
+pub fn parse(input: &str) -> miette::Result<Input> {
+    Ok(Game::from(input))
 }
 
 #[derive(Debug, Default, Clone)]
-struct Game {
+pub struct Game {
     matrix: Matrix,
     guard: Position,
     direction: Direction,
@@ -93,7 +99,7 @@ impl From<&str> for Game {
 }
 
 impl Game {
-    #[tracing::instrument(ret)]
+    #[tracing::instrument(level = "trace", skip(self), fields(guard = ?self.guard))]
     fn update(&mut self) -> GameStatus {
         let move_pos = match self.direction {
             Direction::Up => {
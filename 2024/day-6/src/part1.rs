@@ -1,33 +1,18 @@
-use crate::{Game, GameStatus};
+use aoc_common::answer::Answer;
 
-#[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
-    let mut game = Game::from(input);
+use crate::{parse, GameStatus, Input};
 
+#[tracing::instrument(skip_all)]
+pub fn solve(game: &Input) -> miette::Result<Answer> {
+    let mut game = game.clone();
     loop {
         if let GameStatus::Finished(count) = game.update() {
-            return Ok(count.to_string());
+            return Ok(count.into());
         }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...";
-        assert_eq!("41", process(input)?);
-        Ok(())
-    }
+#[tracing::instrument(skip_all)]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    solve(&parse(input)?)
 }
@@ -1,14 +1,15 @@
-use crate::{Game, GameStatus, Spot};
+use aoc_common::answer::Answer;
 
-#[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
-    let game = Game::from(input);
+use crate::{parse, GameStatus, Input, Spot};
 
+#[tracing::instrument(skip_all)]
+pub fn solve(game: &Input) -> miette::Result<Answer> {
     let mut count = 0;
     for y in 0..game.matrix.len() {
         for x in 0..game.matrix[y].len() {
             match game.matrix[y][x] {
                 Spot::Nothing => {
+                    let _candidate = tracing::debug_span!("candidate", x, y).entered();
                     let mut game = game.clone();
                     game.matrix[y][x] = Spot::Obstacle;
                     loop {
@@ -28,26 +29,10 @@ pub fn process(input: &str) -> miette::Result<String> {
             }
         }
     }
-    Ok(count.to_string())
+    Ok(count.into())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...";
-        assert_eq!("6", process(input)?);
-        Ok(())
-    }
+#[tracing::instrument(skip_all)]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    solve(&parse(input)?)
 }
@@ -1,4 +1,4 @@
-use day_7::part2::process;
+use aoc2024_day07::part2::process;
 use miette::Context;
 
 #[tracing::instrument]
@@ -0,0 +1,59 @@
+use aoc_common::puzzle::Puzzle;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+fn concat(a: u64, b: u64) -> Option<u64> {
+    a.checked_mul(10u64.checked_pow(b.checked_ilog10().unwrap_or(0) + 1)?)?
+        .checked_add(b)
+}
+
+/// Whether some choice of operators, applied left to right, turns `numbers` into `target`.
+fn solvable(target: u64, numbers: &[u64], concatenate: bool) -> bool {
+    fn go(target: u64, total: u64, rest: &[u64], concatenate: bool) -> bool {
+        let Some((&next, rest)) = rest.split_first() else {
+            return total == target;
+        };
+        [
+            total.checked_add(next),
+            total.checked_mul(next),
+            concatenate.then(|| concat(total, next)).flatten(),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|total| total <= target && go(target, total, rest, concatenate))
+    }
+    go(target, numbers[0], &numbers[1..], concatenate)
+}
+
+/// `size` calibration equations of 3 to 7 numbers from 1 to 99. Each target is built by
+/// applying random `+`, `*` and `||` operators, and about a third are then nudged by one,
+/// which usually makes them unsolvable.
+pub fn puzzle(size: usize, seed: u64) -> Puzzle {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0, 0);
+    for _ in 0..size {
+        let numbers: Vec<u64> = (0..rng.gen_range(3..=7))
+            .map(|_| rng.gen_range(1..100))
+            .collect();
+        let mut target =
+            numbers[1..]
+                .iter()
+                .fold(numbers[0], |total, &next| match rng.gen_range(0..3) {
+                    0 => total + next,
+                    1 => total * next,
+                    _ => concat(total, next).unwrap(),
+                });
+        if rng.gen_bool(1.0 / 3.0) {
+            target += 1;
+        }
+        if solvable(target, &numbers, false) {
+            part1 += target;
+        }
+        if solvable(target, &numbers, true) {
+            part2 += target;
+        }
+        let numbers: Vec<String> = numbers.iter().map(u64::to_string).collect();
+        input.push_str(&format!("{target}: {}\n", numbers.join(" ")));
+    }
+    Puzzle::new(input).with_part1(part1).with_part2(part2)
+}
@@ -1,2 +1,31 @@
+pub mod generate;
 pub mod part1;
 pub mod part2;
+
+#[cfg(test)]
+aoc_common::example_tests!();
+
+/// A calibration equation: the test value and the numbers that should combine into it.
+pub type Equation = (u64, Vec<u64>);
+
+pub type Input = Vec<Equation>;
+
+#[tracing::instrument(skip(input), fields(bytes = input.len(), lines = input.lines().count()))]
+pub fn parse(input: &str) -> miette::Result<Input> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let invalid = |e: String| miette::miette!("line {}: {e} in {line:?}", index + 1);
+            let (sum, factors) = line
+                .split_once(": ")
+                .ok_or_else(|| invalid("expected `<value>: <numbers>`".to_string()))?;
+            let sum = sum.parse().map_err(|e| invalid(format!("{e}")))?;
+            let factors = factors
+                .split(' ')
+                .map(|part| part.parse().map_err(|e| invalid(format!("{e}"))))
+                .collect::<miette::Result<_>>()?;
+            Ok((sum, factors))
+        })
+        .collect()
+}
@@ -1,7 +1,10 @@
 use itertools::Itertools;
 
+use aoc_common::answer::Answer;
 use rayon::prelude::*;
 
+use crate::{parse, Input};
+
 #[derive(Debug, Clone, Copy)]
 enum Operation {
     Add,
@@ -9,27 +12,9 @@ enum Operation {
     Concat,
 }
 
-fn parse(input: &str) -> Vec<(u64, Vec<u64>)> {
-    input
-        .lines()
-        .map(|line| {
-            let mut parts = line.split(": ");
-            let sum = parts.next().unwrap().parse().unwrap();
-            let factors = parts
-                .next()
-                .unwrap()
-                .split(' ')
-                .map(|part| part.parse().unwrap())
-                .collect();
-            (sum, factors)
-        })
-        .collect()
-}
-
-#[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
-    let input = parse(input);
-    let count: u64 = input
+#[tracing::instrument(skip_all)]
+pub fn solve(equations: &Input) -> miette::Result<Answer> {
+    let count: u64 = equations
         .par_iter()
         .filter_map(|(sum, factors)| {
             let count = factors.len() - 1;
@@ -53,25 +38,10 @@ pub fn process(input: &str) -> miette::Result<String> {
         })
         .sum();
 
-    Ok(count.to_string())
+    Ok(count.into())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "190: 10 19
-3267: 81 40 27
-83: 17 5
-156: 15 6
-7290: 6 8 6 15
-161011: 16 10 13
-192: 17 8 14
-21037: 9 7 18 13
-292: 11 6 16 20";
-        assert_eq!("11387", process(input)?);
-        Ok(())
-    }
+#[tracing::instrument(skip_all)]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    solve(&parse(input)?)
 }
@@ -1,4 +1,4 @@
-use day_8::part2::process;
+use aoc2024_day08::part2::process;
 use miette::Context;
 
 #[tracing::instrument]
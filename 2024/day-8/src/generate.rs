@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+
+use aoc_common::puzzle::Puzzle;
+use itertools::Itertools;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A square map of about `size` cells with a group of two to four antennas for each of up
+/// to 62 frequencies, about one antenna for every 25 cells.
+pub fn puzzle(size: usize, seed: u64) -> Puzzle {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let side = ((size as f64).sqrt().ceil() as i32).max(2);
+    let mut grid = vec![vec![b'.'; side as usize]; side as usize];
+    let mut cells: Vec<(i32, i32)> = (0..side).cartesian_product(0..side).collect();
+    cells.shuffle(&mut rng);
+    let mut cells = cells.into_iter();
+
+    let mut groups: Vec<Vec<(i32, i32)>> = vec![];
+    for &frequency in FREQUENCIES.iter().take((side * side / 75).max(1) as usize) {
+        let group: Vec<(i32, i32)> = cells.by_ref().take(rng.gen_range(2..=4)).collect();
+        for &(x, y) in &group {
+            grid[y as usize][x as usize] = frequency;
+        }
+        groups.push(group);
+    }
+
+    let inside = |(x, y): (i32, i32)| (0..side).contains(&x) && (0..side).contains(&y);
+    let mut antinodes = HashSet::new();
+    let mut harmonics = HashSet::new();
+    for (&a, &b) in groups
+        .iter()
+        .flat_map(|group| group.iter().tuple_combinations())
+    {
+        let step = (b.0 - a.0, b.1 - a.1);
+        for node in [(b.0 + step.0, b.1 + step.1), (a.0 - step.0, a.1 - step.1)] {
+            if inside(node) {
+                antinodes.insert(node);
+            }
+        }
+        for k in -side..=side {
+            let node = (a.0 + step.0 * k, a.1 + step.1 * k);
+            if inside(node) {
+                harmonics.insert(node);
+            }
+        }
+    }
+
+    let input = grid
+        .into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect();
+    Puzzle::new(input)
+        .with_part1(antinodes.len())
+        .with_part2(harmonics.len())
+}
@@ -1,2 +1,183 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::RangeInclusive,
+};
+
+use glam::IVec2;
+use itertools::Itertools;
+use miette::miette;
+use nom::{
+    bytes::complete::take_till, character::complete::satisfy, multi::many0, sequence::preceded,
+    AsChar, IResult,
+};
+use nom_locate::{position, LocatedSpan};
+
+pub mod generate;
 pub mod part1;
 pub mod part2;
+
+#[cfg(test)]
+aoc_common::example_tests!();
+
+pub type Span<'a> = LocatedSpan<&'a str>;
+
+fn parse_alphanum_pos(input: Span) -> IResult<Span, (IVec2, char)> {
+    let (input, pos) = position(input)?;
+    let x = pos.get_column() as i32 - 1;
+    let y = pos.location_line() as i32 - 1;
+    let (input, c) = satisfy(|c| c.is_alphanum())(input)?;
+    Ok((input, (IVec2::new(x, y), c)))
+}
+
+fn parse_antennas(input: Span) -> IResult<Span, Vec<(IVec2, char)>> {
+    many0(preceded(
+        take_till(|c: char| c.is_alphanum()),
+        parse_alphanum_pos,
+    ))(input)
+}
+
+fn gcd(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+pub type Input = AntennaMap;
+
+#[tracing::instrument(skip(input), fields(bytes = input.len(), lines = input.lines().count()))]
+pub fn parse(input: &str) -> miette::Result<Input> {
+    AntennaMap::parse(input)
+}
+
+#[derive(Debug, Clone)]
+pub struct AntennaMap {
+    size: IVec2,
+    antennas: HashMap<char, Vec<IVec2>>,
+    reduce_directions: bool,
+}
+
+impl AntennaMap {
+    pub fn parse(input: &str) -> miette::Result<Self> {
+        let rows = input.lines().count() as i32;
+        let cols = input.lines().next().map(str::len).unwrap_or_default() as i32;
+        let (_, antennas) = parse_antennas(Span::new(input)).map_err(|e| miette!("{e}"))?;
+        Ok(AntennaMap {
+            size: IVec2::new(cols, rows),
+            antennas: antennas
+                .into_iter()
+                .map(|(pos, c)| (c, pos))
+                .into_group_map(),
+            reduce_directions: false,
+        })
+    }
+
+    /// Step between antinodes by the smallest grid vector along each antenna pair's line.
+    /// Harmonics are then counted in those steps from the first antenna of the pair, so
+    /// `0..=i32::MAX` covers every grid position on the line, including between the antennas.
+    pub fn with_reduced_directions(mut self) -> Self {
+        self.reduce_directions = true;
+        self
+    }
+
+    pub fn contains(&self, pos: IVec2) -> bool {
+        pos.cmpge(IVec2::ZERO).all() && pos.cmplt(self.size).all()
+    }
+
+    /// Every in-bounds antinode at `harmonics` multiples of the distance between each pair
+    /// of same-frequency antennas, in both directions. Harmonics below zero are ignored, and
+    /// open-ended ranges such as `0..=i32::MAX` stop at the edge of the map.
+    pub fn antinodes(&self, harmonics: RangeInclusive<i32>) -> HashSet<IVec2> {
+        let harmonics = (*harmonics.start()).max(0)..=*harmonics.end();
+        self.antennas
+            .values()
+            .flat_map(|antennas| antennas.iter().tuple_combinations())
+            .flat_map(|(&a, &b)| {
+                let step = b - a;
+                if self.reduce_directions {
+                    let step = step / gcd(step.x, step.y);
+                    [(a, step), (a, -step)]
+                } else {
+                    [(b, step), (a, -step)]
+                }
+            })
+            .flat_map(|(start, step)| {
+                harmonics
+                    .clone()
+                    .map(move |k| start + step * k)
+                    .take_while(|&pos| self.contains(pos))
+            })
+            .collect()
+    }
+
+    /// The map with `#` drawn over every empty cell in `antinodes`.
+    pub fn render(&self, antinodes: &HashSet<IVec2>) -> String {
+        let antennas: HashMap<IVec2, char> = self
+            .antennas
+            .iter()
+            .flat_map(|(&c, positions)| positions.iter().map(move |&pos| (pos, c)))
+            .collect();
+        (0..self.size.y)
+            .map(|y| {
+                (0..self.size.x)
+                    .map(|x| {
+                        let pos = IVec2::new(x, y);
+                        match antennas.get(&pos) {
+                            Some(&c) => c,
+                            None if antinodes.contains(&pos) => '#',
+                            None => '.',
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() -> miette::Result<()> {
+        let map = AntennaMap::parse(
+            "..........
+..........
+..........
+....a.....
+..........
+.....a....
+..........
+..........
+..........
+..........",
+        )?;
+        let expected = "..........
+...#......
+..........
+....a.....
+..........
+.....a....
+..........
+......#...
+..........
+..........";
+        assert_eq!(expected, map.render(&map.antinodes(1..=1)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_reduced_directions() -> miette::Result<()> {
+        let input = "a....
+.....
+..a..
+.....
+.....";
+        let map = AntennaMap::parse(input)?;
+        assert_eq!(3, map.antinodes(0..=i32::MAX).len());
+        let map = map.with_reduced_directions();
+        assert_eq!(5, map.antinodes(0..=i32::MAX).len());
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use day_9::part2::process;
+use aoc2024_day09::part2::process;
 use miette::Context;
 
 #[tracing::instrument]
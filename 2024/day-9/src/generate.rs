@@ -0,0 +1,51 @@
+use aoc_common::puzzle::Puzzle;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Checksum once file blocks are moved one at a time from the end into the first gap.
+fn fragmented_checksum(digits: &[usize]) -> usize {
+    let mut blocks: Vec<Option<usize>> = digits
+        .iter()
+        .enumerate()
+        .flat_map(|(index, &size)| std::iter::repeat_n((index % 2 == 0).then_some(index / 2), size))
+        .collect();
+    let (mut free, mut last) = (0, blocks.len());
+    loop {
+        while free < blocks.len() && blocks[free].is_some() {
+            free += 1;
+        }
+        while last > 0 && blocks[last - 1].is_none() {
+            last -= 1;
+        }
+        if free + 1 >= last {
+            break;
+        }
+        blocks.swap(free, last - 1);
+    }
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(index, id)| index * id.unwrap_or_default())
+        .sum()
+}
+
+/// A disk map of `size` digits, rounded up to odd so it ends with a file. Files take 1 to 9
+/// blocks and the free spans between them 0 to 9. Moving whole files takes as much work
+/// as solving part 2, so only part 1 is answered.
+pub fn puzzle(size: usize, seed: u64) -> Puzzle {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let digits: Vec<usize> = (0..size | 1)
+        .map(|index| {
+            if index % 2 == 0 {
+                rng.gen_range(1..=9)
+            } else {
+                rng.gen_range(0..=9)
+            }
+        })
+        .collect();
+    let mut input: String = digits
+        .iter()
+        .map(|&digit| char::from_digit(digit as u32, 10).unwrap())
+        .collect();
+    input.push('\n');
+    Puzzle::new(input).with_part1(fragmented_checksum(&digits))
+}
@@ -1,2 +1,121 @@
+pub mod generate;
 pub mod part1;
 pub mod part2;
+
+#[cfg(test)]
+aoc_common::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::answer::Answer;
+    use proptest::{collection::vec, prelude::*};
+
+    /// Disk maps alternating file sizes of 1 to 9 blocks with free spans of 0 to 9 blocks.
+    fn disk_map() -> impl Strategy<Value = String> {
+        (vec((1..=9u32, 0..=9u32), 0..15), 1..=9u32).prop_map(|(pairs, last)| {
+            pairs
+                .iter()
+                .flat_map(|&(file, free)| [file, free])
+                .chain([last])
+                .map(|size| char::from_digit(size, 10).unwrap())
+                .collect()
+        })
+    }
+
+    /// One entry per block, holding the id of the file stored there.
+    fn blocks(disk_map: &str) -> Vec<Option<usize>> {
+        disk_map
+            .chars()
+            .enumerate()
+            .flat_map(|(index, size)| {
+                let id = (index % 2 == 0).then_some(index / 2);
+                std::iter::repeat_n(id, size.to_digit(10).unwrap() as usize)
+            })
+            .collect()
+    }
+
+    fn checksum(blocks: &[Option<usize>]) -> usize {
+        blocks
+            .iter()
+            .enumerate()
+            .map(|(index, id)| index * id.unwrap_or_default())
+            .sum()
+    }
+
+    /// Moves the last file block into the first free block until no gaps remain.
+    fn naive_fragment(disk_map: &str) -> usize {
+        let mut blocks = blocks(disk_map);
+        while let Some(free) = blocks.iter().position(Option::is_none) {
+            match blocks.iter().rposition(Option::is_some) {
+                Some(last) if last > free => blocks.swap(free, last),
+                _ => break,
+            }
+        }
+        checksum(&blocks)
+    }
+
+    /// Moves each whole file, highest id first, into the leftmost free span that fits it.
+    fn naive_defragment(disk_map: &str) -> usize {
+        let mut blocks = blocks(disk_map);
+        let files = disk_map.len().div_ceil(2);
+        for id in (0..files).rev() {
+            let start = blocks.iter().position(|&block| block == Some(id)).unwrap();
+            let size = blocks.iter().filter(|&&block| block == Some(id)).count();
+            let target =
+                (0..start).find(|&free| blocks[free..free + size].iter().all(Option::is_none));
+            if let Some(free) = target {
+                blocks[free..free + size].fill(Some(id));
+                blocks[start..start + size].fill(None);
+            }
+        }
+        checksum(&blocks)
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_naive(disk_map in disk_map()) {
+            prop_assert_eq!(Answer::from(naive_fragment(&disk_map)), crate::part1::process(&disk_map).unwrap());
+            prop_assert_eq!(Answer::from(naive_defragment(&disk_map)), crate::part2::process(&disk_map).unwrap());
+        }
+    }
+}
+
+/// One block of the disk map: part of the file with this id, or free space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Elem {
+    Id(usize),
+    Dot,
+}
+
+impl std::fmt::Display for Elem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Elem::Id(id) => write!(f, "{}", id),
+            Elem::Dot => write!(f, "."),
+        }
+    }
+}
+
+/// The disk map expanded into one [`Elem`] per block.
+pub type Input = Vec<Elem>;
+
+#[tracing::instrument(skip(input), fields(bytes = input.len(), lines = input.lines().count()))]
+pub fn parse(input: &str) -> miette::Result<Input> {
+    input
+        .trim_end()
+        .chars()
+        .enumerate()
+        .try_fold(Vec::new(), |mut acc, (index, c)| {
+            let num = c
+                .to_digit(10)
+                .ok_or_else(|| miette::miette!("position {index}: expected a digit, found {c:?}"))?
+                as usize;
+            if index % 2 == 0 {
+                let id = index / 2;
+                acc.extend(vec![Elem::Id(id); num]);
+            } else {
+                acc.extend(vec![Elem::Dot; num]);
+            }
+            Ok(acc)
+        })
+}
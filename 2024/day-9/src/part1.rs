@@ -1,37 +1,8 @@
-#[derive(Debug, Clone, Copy)]
-enum Elem {
-    Id(usize),
-    Dot,
-}
+use aoc_common::answer::Answer;
 
-impl std::fmt::Display for Elem {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Elem::Id(id) => write!(f, "{}", id),
-            Elem::Dot => write!(f, "."),
-        }
-    }
-}
+use crate::{parse, Elem, Input};
 
-fn expand(input: &str) -> miette::Result<Vec<Elem>> {
-    let expanded = input
-        .chars()
-        .enumerate()
-        .fold(Vec::new(), |mut acc, (index, c)| {
-            let num = c.to_digit(10).unwrap() as usize;
-            if index % 2 == 0 {
-                let id = index / 2;
-                acc.extend(vec![Elem::Id(id); num]);
-            } else {
-                acc.extend(vec![Elem::Dot; num]);
-            }
-            acc
-        });
-
-    Ok(expanded)
-}
-
-fn compact(input: Vec<Elem>) -> miette::Result<Vec<Elem>> {
+fn compact(input: &[Elem]) -> miette::Result<Vec<Elem>> {
     let free_count = input.iter().filter(|&e| matches!(e, Elem::Dot)).count();
     let back = input
         .iter()
@@ -39,7 +10,7 @@ fn compact(input: Vec<Elem>) -> miette::Result<Vec<Elem>> {
         .filter(|&e| matches!(e, Elem::Id(_)))
         .take(free_count);
     let compacted = back
-        .fold((input.clone(), 0), |(mut elems, mut i), &e| {
+        .fold((input.to_vec(), 0), |(mut elems, mut i), &e| {
             while let Some(Elem::Id(_)) = elems.get(i) {
                 i += 1;
             }
@@ -53,9 +24,8 @@ fn compact(input: Vec<Elem>) -> miette::Result<Vec<Elem>> {
     Ok(compacted)
 }
 
-#[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
-    let expanded = expand(input)?;
+#[tracing::instrument(skip_all)]
+pub fn solve(expanded: &Input) -> miette::Result<Answer> {
     let compacted = compact(expanded)?;
     let checksum = compacted
         .iter()
@@ -64,17 +34,10 @@ pub fn process(input: &str) -> miette::Result<String> {
             Elem::Dot => sum,
             Elem::Id(id) => sum + id * index,
         });
-    Ok(checksum.to_string())
+    Ok(checksum.into())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_checksum() -> miette::Result<()> {
-        let input = "2333133121414131402";
-        assert_eq!("1928", process(input)?);
-        Ok(())
-    }
+#[tracing::instrument(skip_all)]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    solve(&parse(input)?)
 }
@@ -1,37 +1,8 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Elem {
-    Id(usize),
-    Dot,
-}
+use aoc_common::answer::Answer;
 
-impl std::fmt::Display for Elem {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Elem::Id(id) => write!(f, "{}", id),
-            Elem::Dot => write!(f, "."),
-        }
-    }
-}
+use crate::{parse, Elem, Input};
 
-fn expand(input: &str) -> miette::Result<Vec<Elem>> {
-    let expanded = input
-        .chars()
-        .enumerate()
-        .fold(Vec::new(), |mut acc, (index, c)| {
-            let num = c.to_digit(10).unwrap() as usize;
-            if index % 2 == 0 {
-                let id = index / 2;
-                acc.extend(vec![Elem::Id(id); num]);
-            } else {
-                acc.extend(vec![Elem::Dot; num]);
-            }
-            acc
-        });
-
-    Ok(expanded)
-}
-
-fn compact(input: Vec<Elem>) -> miette::Result<Vec<Elem>> {
+fn compact(input: &[Elem]) -> miette::Result<Vec<Elem>> {
     let mut compacted = input
         .iter()
         .enumerate()
@@ -39,9 +10,12 @@ fn compact(input: Vec<Elem>) -> miette::Result<Vec<Elem>> {
         .filter(|&e| matches!(e.1, Elem::Id(_)))
         .collect::<Vec<_>>()
         .chunk_by(|&a, &b| a.1 == b.1)
-        .fold(input.clone(), |mut elems, chunk| {
+        .fold(input.to_vec(), |mut elems, chunk| {
+            // The chunk runs from the file's last block back to its first, and a file only
+            // moves into free space that ends before it starts.
+            let start = chunk[chunk.len() - 1].0;
             let mut i = 0usize;
-            while i < elems.len() && i < chunk[0].0 {
+            while i + chunk.len() <= start {
                 let end = i + chunk.len();
                 if elems[i..end].iter().all(|&e| matches!(e, Elem::Dot)) {
                     (i..end).for_each(|i| elems[i] = *chunk[0].1);
@@ -56,9 +30,8 @@ fn compact(input: Vec<Elem>) -> miette::Result<Vec<Elem>> {
     Ok(compacted)
 }
 
-#[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String> {
-    let expanded = expand(input)?;
+#[tracing::instrument(skip_all)]
+pub fn solve(expanded: &Input) -> miette::Result<Answer> {
     let compacted = compact(expanded)?;
     let checksum = compacted
         .iter()
@@ -67,17 +40,10 @@ pub fn process(input: &str) -> miette::Result<String> {
             Elem::Dot => sum,
             Elem::Id(id) => sum + id * index,
         });
-    Ok(checksum.to_string())
+    Ok(checksum.into())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_checksum() -> miette::Result<()> {
-        let input = "2333133121414131402";
-        assert_eq!("2858", process(input)?);
-        Ok(())
-    }
+#[tracing::instrument(skip_all)]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    solve(&parse(input)?)
 }
@@ -0,0 +1,285 @@
+use std::{
+    any::Any,
+    cell::RefCell,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicU8, AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc, Condvar, Mutex, Once,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Worker threads are named with this prefix, so the panic hook knows to keep quiet.
+const WORKER: &str = "aoc-worker";
+
+/// Workers that outlived their timeout and are still running.
+static RUNAWAYS: AtomicUsize = AtomicUsize::new(0);
+
+/// Worker states, so that exactly one of the worker finishing and the caller giving up
+/// wins, and a runaway is counted until it really stops.
+const RUNNING: u8 = 0;
+const FINISHED: u8 = 1;
+const ABANDONED: u8 = 2;
+
+thread_local! {
+    /// Where the last panic on this thread was raised, recorded by the hook because
+    /// `catch_unwind` only hands back the payload.
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// How work run by [`isolate`] ended.
+#[derive(Debug)]
+pub enum Outcome<T> {
+    Done { value: T, elapsed: Duration },
+    Failed(miette::Report),
+    Panicked(Panic),
+    TimedOut(Duration),
+}
+
+impl<T> Outcome<T> {
+    /// What went wrong, or `None` if the work finished.
+    pub fn failure(&self) -> Option<String> {
+        match self {
+            Outcome::Done { .. } => None,
+            Outcome::Failed(report) => Some(format!(
+                "ERROR: {}",
+                report
+                    .chain()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(": ")
+            )),
+            Outcome::Panicked(panic) => Some(panic.to_string()),
+            Outcome::TimedOut(timeout) => Some(format!("TIMEOUT after {timeout:?}")),
+        }
+    }
+}
+
+/// A caught panic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// `file:line:column` of the `panic!`, `unwrap` or index that raised it.
+    pub location: Option<String>,
+}
+
+impl fmt::Display for Panic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "PANIC at {location}: {}", self.message),
+            None => write!(f, "PANIC: {}", self.message),
+        }
+    }
+}
+
+/// Records panic locations on worker threads instead of printing them, and leaves every
+/// other thread to the hook that was there before.
+fn install_hook() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let worker = thread::current()
+                .name()
+                .is_some_and(|name| name.starts_with(WORKER));
+            if worker {
+                let location = info.location().map(ToString::to_string);
+                LOCATION.with(|last| *last.borrow_mut() = location);
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// A counting semaphore that caps how many workers run at once, since each [`isolate`]
+/// call starts a thread of its own however many threads the caller has.
+#[derive(Debug)]
+pub struct Slots {
+    free: Mutex<usize>,
+    freed: Condvar,
+}
+
+impl Slots {
+    pub fn new(slots: usize) -> Self {
+        Slots {
+            free: Mutex::new(slots.max(1)),
+            freed: Condvar::new(),
+        }
+    }
+
+    /// Waits for a free slot and holds it while `work` runs. A worker that times out gives
+    /// its slot back, and is counted by [`runaways`] instead.
+    pub fn run<T>(&self, work: impl FnOnce() -> T) -> T {
+        let mut free = self.free.lock().unwrap_or_else(|e| e.into_inner());
+        while *free == 0 {
+            free = self.freed.wait(free).unwrap_or_else(|e| e.into_inner());
+        }
+        *free -= 1;
+        drop(free);
+        let output = work();
+        *self.free.lock().unwrap_or_else(|e| e.into_inner()) += 1;
+        self.freed.notify_one();
+        output
+    }
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "panicked with a non-string payload".to_string())
+}
+
+/// How many workers timed out but are still running. Their CPU time and allocations land
+/// on whatever is measured meanwhile, so nothing timed while this is above zero can be
+/// trusted.
+pub fn runaways() -> usize {
+    RUNAWAYS.load(Ordering::SeqCst)
+}
+
+/// Runs `work` on a thread of its own, catching any panic and giving up on it after
+/// `timeout`. Rust cannot stop a thread, so one that times out keeps running in the
+/// background, and counts towards [`runaways`] until it finishes.
+pub fn isolate<T: Send + 'static>(
+    name: &str,
+    timeout: Duration,
+    work: impl FnOnce() -> miette::Result<T> + Send + 'static,
+) -> Outcome<T> {
+    install_hook();
+    let (sender, receiver) = mpsc::channel();
+    let state = Arc::new(AtomicU8::new(RUNNING));
+    let worker_state = Arc::clone(&state);
+    let spawned = thread::Builder::new()
+        .name(format!("{WORKER} {name}"))
+        .spawn(move || {
+            let start = Instant::now();
+            let result = panic::catch_unwind(AssertUnwindSafe(work));
+            let elapsed = start.elapsed();
+            let outcome = match result {
+                Ok(Ok(value)) => Outcome::Done { value, elapsed },
+                Ok(Err(report)) => Outcome::Failed(report),
+                Err(payload) => Outcome::Panicked(Panic {
+                    message: message(&*payload),
+                    location: LOCATION.with(|last| last.borrow_mut().take()),
+                }),
+            };
+            let finished = worker_state.compare_exchange(
+                RUNNING,
+                FINISHED,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            );
+            if finished.is_err() {
+                // Nobody is listening any more: the work ran past its timeout.
+                RUNAWAYS.fetch_sub(1, Ordering::SeqCst);
+                return;
+            }
+            let _ = sender.send(outcome);
+        });
+    if let Err(e) = spawned {
+        return Outcome::Failed(miette::miette!("spawn a worker for {name}: {e}"));
+    }
+    match receiver.recv_timeout(timeout) {
+        Ok(outcome) => outcome,
+        Err(RecvTimeoutError::Timeout) => {
+            // Count the runaway before the worker can see it abandoned and uncount it.
+            RUNAWAYS.fetch_add(1, Ordering::SeqCst);
+            let abandoned =
+                state.compare_exchange(RUNNING, ABANDONED, Ordering::SeqCst, Ordering::SeqCst);
+            if abandoned.is_ok() {
+                return Outcome::TimedOut(timeout);
+            }
+            // It finished just as the time ran out, so its outcome is on its way.
+            RUNAWAYS.fetch_sub(1, Ordering::SeqCst);
+            receiver.recv().unwrap_or_else(|_| {
+                Outcome::Failed(miette::miette!(
+                    "the worker for {name} exited without a result"
+                ))
+            })
+        }
+        Err(RecvTimeoutError::Disconnected) => Outcome::Failed(miette::miette!(
+            "the worker for {name} exited without a result"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    #[test]
+    fn test_outcomes() {
+        let done = isolate("done", TIMEOUT, || Ok(42));
+        assert!(matches!(done, Outcome::Done { value: 42, .. }));
+        assert_eq!(None, done.failure());
+
+        let failed = isolate("failed", TIMEOUT, || -> miette::Result<()> {
+            Err(miette::miette!("no digit found"))
+        });
+        assert_eq!(Some("ERROR: no digit found".to_string()), failed.failure());
+
+        let stop = Arc::new(AtomicU8::new(0));
+        let running = Arc::clone(&stop);
+        let timed_out = isolate(
+            "timed out",
+            Duration::from_millis(50),
+            move || -> miette::Result<()> {
+                while running.load(Ordering::SeqCst) == 0 {
+                    thread::sleep(Duration::from_millis(10));
+                }
+                Ok(())
+            },
+        );
+        assert!(matches!(timed_out, Outcome::TimedOut(_)));
+        assert_eq!(1, runaways());
+        stop.store(1, Ordering::SeqCst);
+        let start = Instant::now();
+        while runaways() > 0 && start.elapsed() < TIMEOUT {
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(0, runaways());
+    }
+
+    #[test]
+    fn test_slots() {
+        let slots = Slots::new(2);
+        let (running, most) = (AtomicUsize::new(0), AtomicUsize::new(0));
+        thread::scope(|scope| {
+            for _ in 0..6 {
+                scope.spawn(|| {
+                    slots.run(|| {
+                        let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                        most.fetch_max(now, Ordering::SeqCst);
+                        thread::sleep(Duration::from_millis(20));
+                        running.fetch_sub(1, Ordering::SeqCst);
+                    })
+                });
+            }
+        });
+        assert_eq!(2, most.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_panic_location() {
+        let line = line!() + 2;
+        let outcome = isolate("panicked", TIMEOUT, || -> miette::Result<()> {
+            panic!("Invalid move {}", '?')
+        });
+        let Outcome::Panicked(panic) = outcome else {
+            panic!("expected a panic, got {outcome:?}");
+        };
+        assert_eq!("Invalid move ?", panic.message);
+        let location = panic.location.expect("the hook records where it panicked");
+        assert!(
+            location.contains(&format!("isolate.rs:{line}:")),
+            "{location}"
+        );
+    }
+}
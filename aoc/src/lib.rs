@@ -0,0 +1,6 @@
+pub mod isolate;
+#[cfg(unix)]
+pub mod profile;
+pub mod registry;
+pub mod scaffold;
+pub mod summary;
@@ -0,0 +1,414 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+#[cfg(unix)]
+use aoc::profile;
+use aoc::{
+    isolate::{self, isolate, Outcome, Slots},
+    registry::{self, Parsed, Solution},
+    scaffold,
+    summary::{Summary, Timing},
+};
+use clap::{Parser, Subcommand};
+use miette::{Context, IntoDiagnostic};
+use rayon::prelude::*;
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::{prelude::*, EnvFilter};
+
+#[cfg(feature = "alloc")]
+#[global_allocator]
+static ALLOC: aoc_common::alloc::CountingAlloc = aoc_common::alloc::CountingAlloc::new();
+
+/// Allocations since the last call, when the runner is built with the `alloc` feature.
+fn alloc_report() -> String {
+    #[cfg(feature = "alloc")]
+    {
+        let report = format!(", {}", ALLOC.stats());
+        ALLOC.reset();
+        report
+    }
+    #[cfg(not(feature = "alloc"))]
+    String::new()
+}
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions across every year")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Write a Chrome trace of every span to this file, for chrome://tracing or Perfetto.
+    /// Spans are recorded at the level in `AOC_TRACE`, `debug` by default.
+    #[arg(long, global = true)]
+    trace: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day's solution against its puzzle input, or every day of the year.
+    Run {
+        #[arg(long, default_value_t = 2024)]
+        year: u16,
+        /// Run a single day instead of the whole year.
+        #[arg(long, conflicts_with = "all")]
+        day: Option<u8>,
+        /// Run every registered day of every year.
+        #[arg(long)]
+        all: bool,
+        /// Run days and parts at the same time on a thread pool. Faster overall, but each
+        /// part's time includes contention with the others and allocations go uncounted.
+        #[arg(long)]
+        parallel: bool,
+        /// Seconds the parses and parts of every day run should add up to at most, checked
+        /// in the summary printed after more than one day.
+        #[arg(long, default_value = "1", value_parser = seconds)]
+        budget: Duration,
+        /// Run a single part instead of both.
+        #[arg(long)]
+        part: Option<u8>,
+        /// Give up on a part, or on parsing, after this many seconds.
+        #[arg(long, default_value_t = 60)]
+        timeout: u64,
+    },
+    /// Profile a part with a sampling profiler, writing a flamegraph and folded stacks.
+    /// Only available on Unix.
+    #[cfg(unix)]
+    Profile {
+        #[arg(long, default_value_t = 2024)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        part: u8,
+        /// Keep re-running the part for at least this many seconds.
+        #[arg(long, default_value_t = 5)]
+        seconds: u64,
+        /// Directory to write the profile to.
+        #[arg(long, default_value = "profiles")]
+        out: PathBuf,
+    },
+    /// Generate a synthetic input, writing its known answers to an `.answers` sidecar.
+    Generate {
+        #[arg(long, default_value_t = 2024)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        /// Roughly how many lines, cells or items the input should have.
+        #[arg(long, default_value_t = 1000)]
+        size: usize,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Where to write the input, such as `2024/day-6/examples/generated.txt`. Prints
+        /// it to stdout when left out.
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Scaffold a new day's crate from the daily template and register it.
+    New {
+        #[arg(long, default_value_t = 2024)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+    },
+}
+
+#[tracing::instrument(skip(cli))]
+fn run(cli: Cli) -> miette::Result<()> {
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            all,
+            parallel,
+            budget,
+            part,
+            timeout,
+        } => {
+            let solutions: Vec<&'static Solution> = match day {
+                Some(day) => vec![registry::find(year, day).ok_or_else(|| {
+                    miette::miette!("no solution registered for {year} day {day}")
+                })?],
+                None => registry::SOLUTIONS
+                    .iter()
+                    .filter(|solution| all || solution.year == year)
+                    .collect(),
+            };
+            if solutions.is_empty() {
+                return Err(miette::miette!("no solutions registered for {year}"));
+            }
+            let parts = part.map_or(vec![1, 2], |part| vec![part]);
+            let timeout = Duration::from_secs(timeout);
+            let start = Instant::now();
+            let mut threads = 1;
+            let timings: Vec<Timing> = if parallel {
+                // The runner's tasks spend their time blocked on workers, so they get a pool
+                // of their own. Days that use rayon inside a part, such as 2024 day 7, would
+                // otherwise wait for a global pool that the runner has filled.
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .thread_name(|index| format!("aoc-runner {index}"))
+                    .build()
+                    .into_diagnostic()
+                    .context("build the runner's thread pool")?;
+                threads = pool.current_num_threads();
+                // No more workers than the pool has threads, so steps only share cores
+                // with each other as much as the pool size says.
+                let slots = Slots::new(threads);
+                // Print whole days in order once they are all done, rather than interleaved.
+                let days: Vec<DayRun> = pool.install(|| {
+                    solutions
+                        .par_iter()
+                        .map(|solution| run_day(solution, &parts, timeout, Some(&slots)))
+                        .collect()
+                });
+                days.into_iter().flat_map(DayRun::print).collect()
+            } else {
+                solutions
+                    .iter()
+                    .flat_map(|solution| run_day(solution, &parts, timeout, None).print())
+                    .collect()
+            };
+            let wall = start.elapsed();
+            let failed = timings
+                .iter()
+                .filter(|timing| timing.elapsed.is_none())
+                .count();
+            if solutions.len() > 1 {
+                let summary = Summary::new(timings, budget);
+                println!("\n{summary}");
+                if parallel {
+                    println!("ran in {wall:?} on {threads} thread(s)");
+                }
+            }
+            if failed > 0 {
+                return Err(miette::miette!("{failed} step(s) did not finish"));
+            }
+        }
+        #[cfg(unix)]
+        Command::Profile {
+            year,
+            day,
+            part,
+            seconds,
+            out,
+        } => {
+            let solution = registry::find(year, day)
+                .ok_or_else(|| miette::miette!("no solution registered for {year} day {day}"))?;
+            let profile = profile::profile(solution, part, Duration::from_secs(seconds), &out)
+                .with_context(|| format!("profile {year} day {day} part {part}"))?;
+            println!(
+                "wrote {} and {} from {} samples over {} runs",
+                profile.flamegraph.display(),
+                profile.folded.display(),
+                profile.samples,
+                profile.runs
+            );
+        }
+        Command::Generate {
+            year,
+            day,
+            size,
+            seed,
+            out,
+        } => {
+            let solution = registry::find(year, day)
+                .ok_or_else(|| miette::miette!("no solution registered for {year} day {day}"))?;
+            let puzzle = (solution.generate)(size, seed);
+            match out {
+                Some(path) => {
+                    let sidecar = path.with_extension("answers");
+                    fs::write(&path, &puzzle.input)
+                        .into_diagnostic()
+                        .with_context(|| format!("write {}", path.display()))?;
+                    fs::write(&sidecar, puzzle.answers())
+                        .into_diagnostic()
+                        .with_context(|| format!("write {}", sidecar.display()))?;
+                    println!("wrote {} and {}", path.display(), sidecar.display());
+                }
+                None => print!("{}", puzzle.input),
+            }
+        }
+        Command::New { year, day } => {
+            let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .expect("the runner lives inside the workspace");
+            let dir = scaffold::new_day(root, year, day)?;
+            println!("created {}", dir.display());
+        }
+    }
+    Ok(())
+}
+
+/// What running a day printed, and how long each of its steps took.
+struct DayRun {
+    lines: Vec<String>,
+    timings: Vec<Timing>,
+}
+
+impl DayRun {
+    fn print(self) -> Vec<Timing> {
+        for line in self.lines {
+            println!("{line}");
+        }
+        self.timings
+    }
+}
+
+/// Parses a day's input and runs `parts` against it, each on a worker thread, recording an
+/// answer or how the step failed. A failed parse skips the parts. With `slots` the parts
+/// run at the same time, taking a slot each, and allocations go uncounted since other
+/// days share the allocator.
+fn run_day(
+    solution: &'static Solution,
+    parts: &[u8],
+    timeout: Duration,
+    slots: Option<&Slots>,
+) -> DayRun {
+    let (year, day) = (solution.year, solution.day);
+    let parallel = slots.is_some();
+    let memory = || {
+        if parallel {
+            String::new()
+        } else {
+            alloc_report()
+        }
+    };
+    let mut run = DayRun {
+        lines: Vec::new(),
+        timings: Vec::new(),
+    };
+
+    let name = format!("{year} day {day} parse");
+    let runaways = isolate::runaways();
+    memory();
+    let parsing = isolate_in(slots, &name, timeout, move || {
+        (solution.parse)(solution.input)
+    });
+    let parse_memory = memory();
+    let contaminated = contamination(runaways);
+    let parsed: Arc<Parsed> = match parsing {
+        Outcome::Done { value, elapsed } => {
+            run.lines
+                .push(format!("{name} ({elapsed:?}{parse_memory}){contaminated}"));
+            run.timings.push(Timing {
+                name,
+                elapsed: Some(elapsed),
+                contaminated: !contaminated.is_empty(),
+            });
+            value.into()
+        }
+        failed => {
+            let failure = failed.failure().unwrap_or_default();
+            run.lines.push(format!("{name}: {failure}"));
+            run.timings.push(Timing {
+                name,
+                elapsed: None,
+                contaminated: false,
+            });
+            return run;
+        }
+    };
+
+    let run_part = |part: u8| {
+        let name = format!("{year} day {day} part {part}");
+        let parsed = Arc::clone(&parsed);
+        let runaways = isolate::runaways();
+        memory();
+        let outcome = isolate_in(slots, &name, timeout, move || {
+            solution.solve(part, &*parsed)
+        });
+        let memory = memory();
+        let contaminated = contamination(runaways);
+        let (line, elapsed) = match outcome {
+            Outcome::Done { value, elapsed } => (
+                value.line(&name, &format!("({elapsed:?}{memory}){contaminated}")),
+                Some(elapsed),
+            ),
+            failed => (
+                format!("{name}: {}", failed.failure().unwrap_or_default()),
+                None,
+            ),
+        };
+        let contaminated = elapsed.is_some() && !contaminated.is_empty();
+        (
+            line,
+            Timing {
+                name,
+                elapsed,
+                contaminated,
+            },
+        )
+    };
+    let results: Vec<(String, Timing)> = if parallel {
+        parts.par_iter().map(|&part| run_part(part)).collect()
+    } else {
+        parts.iter().map(|&part| run_part(part)).collect()
+    };
+    for (line, timing) in results {
+        run.lines.push(line);
+        run.timings.push(timing);
+    }
+    run
+}
+
+/// Runs `work` through [`isolate`], waiting for one of `slots` first when there are any.
+fn isolate_in<T: Send + 'static>(
+    slots: Option<&Slots>,
+    name: &str,
+    timeout: Duration,
+    work: impl FnOnce() -> miette::Result<T> + Send + 'static,
+) -> Outcome<T> {
+    match slots {
+        Some(slots) => slots.run(|| isolate(name, timeout, work)),
+        None => isolate(name, timeout, work),
+    }
+}
+
+/// Reads a positive number of seconds, which may be fractional.
+fn seconds(arg: &str) -> Result<Duration, String> {
+    let seconds: f64 = arg.parse().map_err(|e| format!("{arg}: {e}"))?;
+    match Duration::try_from_secs_f64(seconds) {
+        Ok(duration) if !duration.is_zero() => Ok(duration),
+        _ => Err(format!("{arg} is not a positive number of seconds")),
+    }
+}
+
+/// A warning for a step's line when a worker that timed out was still running when the
+/// step started or ended, given how many were running when it started. Empty otherwise.
+fn contamination(runaways_before: usize) -> String {
+    match runaways_before.max(isolate::runaways()) {
+        0 => String::new(),
+        runaways => format!(" [timed alongside {runaways} timed-out worker(s)]"),
+    }
+}
+
+/// Logs events to stderr as filtered by `RUST_LOG`, and with `trace` also records spans to
+/// a Chrome trace file under its own filter. The trace is written when the guard drops.
+fn init_tracing(trace: Option<&Path>) -> Option<FlushGuard> {
+    let (chrome, guard) = match trace {
+        Some(path) => {
+            let (layer, guard) = ChromeLayerBuilder::new()
+                .file(path)
+                .include_args(true)
+                .build();
+            let filter =
+                EnvFilter::try_from_env("AOC_TRACE").unwrap_or_else(|_| EnvFilter::new("debug"));
+            (Some(layer.with_filter(filter)), Some(guard))
+        }
+        None => (None, None),
+    };
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer().with_filter(EnvFilter::from_default_env()))
+        .with(chrome)
+        .init();
+    guard
+}
+
+fn main() -> miette::Result<()> {
+    let cli = Cli::parse();
+    let _guard = init_tracing(cli.trace.as_deref());
+
+    run(cli)
+}
@@ -0,0 +1,125 @@
+use std::{
+    fmt::Write as _,
+    fs::{self, File},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use miette::{Context, IntoDiagnostic};
+use pprof::{ProfilerGuardBuilder, Report};
+
+use crate::registry::Solution;
+
+/// Samples per second. A prime keeps the samples from lining up with periodic work.
+const FREQUENCY: i32 = 997;
+
+/// Where a profile was written and how much it saw.
+#[derive(Debug)]
+pub struct Profile {
+    pub runs: u32,
+    pub samples: isize,
+    pub flamegraph: PathBuf,
+    pub folded: PathBuf,
+}
+
+/// Runs `part` of `solution` over and over for at least `duration` under a sampling
+/// profiler, then writes an SVG flamegraph and the folded stacks it was drawn from to
+/// `<out>/<year>-day<DD>-part<P>.{svg,folded}`.
+pub fn profile(
+    solution: &Solution,
+    part: u8,
+    duration: Duration,
+    out: &Path,
+) -> miette::Result<Profile> {
+    let guard = ProfilerGuardBuilder::default()
+        .frequency(FREQUENCY)
+        .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+        .build()
+        .into_diagnostic()
+        .context("start the profiler")?;
+    let start = Instant::now();
+    let mut runs = 0;
+    while runs == 0 || start.elapsed() < duration {
+        solution.run(part)?;
+        runs += 1;
+    }
+    let report = guard
+        .report()
+        .build()
+        .into_diagnostic()
+        .context("build the profile")?;
+
+    fs::create_dir_all(out)
+        .into_diagnostic()
+        .with_context(|| format!("create {}", out.display()))?;
+    let name = format!("{}-day{:02}-part{part}", solution.year, solution.day);
+    let flamegraph = out.join(format!("{name}.svg"));
+    let folded = out.join(format!("{name}.folded"));
+    let svg = File::create(&flamegraph)
+        .into_diagnostic()
+        .with_context(|| format!("create {}", flamegraph.display()))?;
+    report
+        .flamegraph(svg)
+        .into_diagnostic()
+        .with_context(|| format!("write {}", flamegraph.display()))?;
+    fs::write(&folded, fold(&report))
+        .into_diagnostic()
+        .with_context(|| format!("write {}", folded.display()))?;
+
+    Ok(Profile {
+        runs,
+        samples: report.data.values().sum(),
+        flamegraph,
+        folded,
+    })
+}
+
+/// One `thread;outermost;...;innermost count` line per distinct stack, the format read by
+/// `inferno-flamegraph` and `flamegraph.pl`, sorted so profiles diff cleanly.
+fn fold(report: &Report) -> String {
+    let mut lines: Vec<String> = report
+        .data
+        .iter()
+        .map(|(frames, count)| {
+            let mut line = frames.thread_name_or_id();
+            for symbol in frames
+                .frames
+                .iter()
+                .rev()
+                .flat_map(|frame| frame.iter().rev())
+            {
+                write!(line, ";{symbol}").unwrap();
+            }
+            write!(line, " {count}").unwrap();
+            line
+        })
+        .collect();
+    lines.sort();
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_profile() -> miette::Result<()> {
+        let solution = registry::find(2023, 1).expect("2023 day 1 is registered");
+        let out = std::env::temp_dir().join(format!("aoc-profile-{}", std::process::id()));
+        let profile = profile(solution, 1, Duration::from_millis(300), &out)?;
+        assert!(profile.runs > 0);
+        assert_eq!(
+            "2023-day01-part1.svg",
+            profile.flamegraph.file_name().unwrap()
+        );
+        let folded = fs::read_to_string(&profile.folded).into_diagnostic()?;
+        let counted: isize = folded
+            .lines()
+            .map(|line| line.rsplit_once(' ').unwrap().1.parse::<isize>().unwrap())
+            .sum();
+        assert_eq!(profile.samples, counted);
+        fs::remove_dir_all(out).into_diagnostic()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+use std::any::Any;
+
+use aoc_common::{answer::Answer, puzzle::Puzzle};
+
+/// A day's `Input`, whichever type its `parse` returns.
+pub type Parsed = dyn Any + Send + Sync;
+
+/// Parses a day's puzzle input once, for both parts to share.
+pub type Parse = fn(&str) -> miette::Result<Box<Parsed>>;
+
+/// One part of a day's solution, taking the parsed input.
+pub type Part = fn(&Parsed) -> miette::Result<Answer>;
+
+/// Builds a synthetic input of about `size` items from a seed.
+pub type Generate = fn(usize, u64) -> Puzzle;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub input: &'static str,
+    pub parse: Parse,
+    pub parts: [Part; 2],
+    pub generate: Generate,
+}
+
+impl Solution {
+    /// Runs `part` (1 or 2) against the day's puzzle input.
+    pub fn run(&self, part: u8) -> miette::Result<Answer> {
+        self.run_on(part, self.input)
+    }
+
+    /// Parses `input` and runs `part` against it instead of the day's puzzle input.
+    pub fn run_on(&self, part: u8, input: &str) -> miette::Result<Answer> {
+        let parsed = (self.parse)(input)?;
+        self.solve(part, &*parsed)
+    }
+
+    /// Runs `part` against input already parsed by this day's `parse`.
+    pub fn solve(&self, part: u8, parsed: &Parsed) -> miette::Result<Answer> {
+        let solve = part
+            .checked_sub(1)
+            .and_then(|index| self.parts.get(index as usize))
+            .ok_or_else(|| miette::miette!("part {part} does not exist, expected 1 or 2"))?;
+        solve(parsed)
+    }
+}
+
+/// The day's own `Input` back out of what its `parse` returned.
+fn downcast<T: 'static>(parsed: &Parsed) -> &T {
+    parsed
+        .downcast_ref()
+        .expect("parts are only given their own day's parsed input")
+}
+
+/// Registers `(year, day) => crate` entries, where the crate lives in `<year>/day-<day>`.
+macro_rules! solutions {
+    ($(($year:literal, $day:literal) => $krate:ident,)*) => {
+        pub const SOLUTIONS: &[Solution] = &[$(
+            Solution {
+                year: $year,
+                day: $day,
+                input: include_str!(concat!("../../", $year, "/day-", $day, "/input.txt")),
+                parse: |input| Ok(Box::new($krate::parse(input)?)),
+                parts: [
+                    |parsed| $krate::part1::solve(downcast(parsed)),
+                    |parsed| $krate::part2::solve(downcast(parsed)),
+                ],
+                generate: $krate::generate::puzzle,
+            },
+        )*];
+    };
+}
+
+solutions! {
+    (2023, 1) => aoc2023_day01,
+    (2024, 1) => aoc2024_day01,
+    (2024, 2) => aoc2024_day02,
+    (2024, 3) => aoc2024_day03,
+    (2024, 4) => aoc2024_day04,
+    (2024, 5) => aoc2024_day05,
+    (2024, 6) => aoc2024_day06,
+    (2024, 7) => aoc2024_day07,
+    (2024, 8) => aoc2024_day08,
+    (2024, 9) => aoc2024_day09,
+    (2024, 10) => aoc2024_day10,
+    (2024, 11) => aoc2024_day11,
+    (2024, 12) => aoc2024_day12,
+    (2024, 13) => aoc2024_day13,
+    (2024, 14) => aoc2024_day14,
+    (2024, 15) => aoc2024_day15,
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Solution> {
+    SOLUTIONS
+        .iter()
+        .find(|solution| solution.year == year && solution.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answer::Unsolved;
+
+    #[test]
+    fn test_registered_once() {
+        for (index, solution) in SOLUTIONS.iter().enumerate() {
+            assert!(
+                SOLUTIONS[index + 1..]
+                    .iter()
+                    .all(|other| (other.year, other.day) != (solution.year, solution.day)),
+                "{} day {} is registered twice",
+                solution.year,
+                solution.day
+            );
+        }
+        assert!(find(2023, 1).is_some());
+        assert!(find(2023, 25).is_none());
+    }
+
+    /// Parts too slow to run on generated inputs in a debug build. Day 11 part 1 grows a
+    /// `Vec` of every stone, and its proptest already checks it against part 2.
+    const SLOW: &[(u16, u8, u8)] = &[(2024, 11, 1)];
+
+    #[test]
+    fn test_generated_answers() -> miette::Result<()> {
+        for solution in SOLUTIONS {
+            for seed in 0..3 {
+                let puzzle = (solution.generate)(64, seed);
+                let parsed = (solution.parse)(&puzzle.input)?;
+                for part in [1, 2] {
+                    if SLOW.contains(&(solution.year, solution.day, part)) {
+                        continue;
+                    }
+                    let Some(expected) = puzzle.answer(part) else {
+                        continue;
+                    };
+                    let answer = match solution.solve(part, &*parsed) {
+                        Err(report) if Unsolved::is(&report) => continue,
+                        answer => answer?,
+                    };
+                    assert_eq!(
+                        *expected, answer,
+                        "{} day {} part {part}, seed {seed}",
+                        solution.year, solution.day
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+}
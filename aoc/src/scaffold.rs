@@ -0,0 +1,248 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use miette::{Context, IntoDiagnostic};
+
+/// Files of a new day's crate, relative to its directory, read from `daily-template`.
+const TEMPLATE: &[(&str, &str)] = &[
+    (
+        "Cargo.toml",
+        include_str!("../../daily-template/Cargo.toml"),
+    ),
+    ("input.txt", include_str!("../../daily-template/input.txt")),
+    (
+        "examples/1.txt",
+        include_str!("../../daily-template/examples/1.txt"),
+    ),
+    (
+        "examples/1.answers",
+        include_str!("../../daily-template/examples/1.answers"),
+    ),
+    (
+        "benches/benchmarks.rs",
+        include_str!("../../daily-template/benches/benchmarks.rs"),
+    ),
+    (
+        "benches/instructions.rs",
+        include_str!("../../daily-template/benches/instructions.rs"),
+    ),
+    (
+        "benches/scaling.rs",
+        include_str!("../../daily-template/benches/scaling.rs"),
+    ),
+    (
+        "src/lib.rs",
+        include_str!("../../daily-template/src/lib.rs"),
+    ),
+    (
+        "src/generate.rs",
+        include_str!("../../daily-template/src/generate.rs"),
+    ),
+    (
+        "src/part1.rs",
+        include_str!("../../daily-template/src/part1.rs"),
+    ),
+    (
+        "src/part2.rs",
+        include_str!("../../daily-template/src/part2.rs"),
+    ),
+    (
+        "src/bin/part1.rs",
+        include_str!("../../daily-template/src/bin/part1.rs"),
+    ),
+    (
+        "src/bin/part2.rs",
+        include_str!("../../daily-template/src/bin/part2.rs"),
+    ),
+];
+
+/// Package name of a day's crate, such as `aoc2024-day06`.
+pub fn package_name(year: u16, day: u8) -> String {
+    format!("aoc{year}-day{day:02}")
+}
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    let package = package_name(year, day);
+    template
+        .replace("{{project-name}}", &package)
+        .replace("{{crate_name}}", &package.replace('-', "_"))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Adds `<year>/day-*` to the workspace `members` and `default-members` if missing.
+fn register_member(manifest: &str, year: u16) -> miette::Result<String> {
+    let member = format!("\"{year}/day-*\"");
+    let mut found = false;
+    let lines = manifest.lines().map(|line| {
+        if !(line.starts_with("members = [") || line.starts_with("default-members = [")) {
+            return Ok(line.to_string());
+        }
+        found = true;
+        if line.contains(&member) {
+            return Ok(line.to_string());
+        }
+        let end = line
+            .rfind(']')
+            .ok_or_else(|| miette::miette!("expected a single-line list in {line:?}"))?;
+        Ok(format!("{}, {member}{}", &line[..end], &line[end..]))
+    });
+    let manifest = lines.collect::<miette::Result<Vec<_>>>()?.join("\n") + "\n";
+    if !found {
+        return Err(miette::miette!("no workspace members found in Cargo.toml"));
+    }
+    Ok(manifest)
+}
+
+/// Inserts `entry` into the first contiguous block of lines matching `is_entry`, keeping
+/// the block sorted and rejecting duplicates.
+fn insert_sorted(
+    text: &str,
+    is_entry: impl Fn(&str) -> bool,
+    entry: String,
+) -> miette::Result<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| is_entry(line))
+        .ok_or_else(|| miette::miette!("could not find where to insert {entry:?}"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| !is_entry(line))
+            .unwrap_or(lines.len() - start);
+    if lines[start..end].contains(&entry.as_str()) {
+        return Err(miette::miette!("{entry:?} is already registered"));
+    }
+    let mut block = lines[start..end].to_vec();
+    block.push(&entry);
+    block.sort_by_key(|line| sort_key(line));
+
+    let lines: Vec<&str> = [&lines[..start], &block, &lines[end..]].concat();
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Orders registry lines by year and then day, which plain string order gets wrong for
+/// `(2024, 9)` against `(2024, 10)`.
+fn sort_key(line: &str) -> Vec<u32> {
+    line.split(|c: char| !c.is_ascii_digit())
+        .filter_map(|number| number.parse().ok())
+        .collect()
+}
+
+/// Adds the day's crate as a dependency of the runner.
+fn register_dependency(manifest: &str, year: u16, day: u8) -> miette::Result<String> {
+    let entry = format!(
+        "{} = {{ path = \"../{year}/day-{day}\" }}",
+        package_name(year, day)
+    );
+    insert_sorted(manifest, |line| line.starts_with("aoc2"), entry)
+}
+
+/// Adds the day to the `solutions!` list of the registry.
+fn register_solution(registry: &str, year: u16, day: u8) -> miette::Result<String> {
+    let entry = format!(
+        "    ({year}, {day}) => {},",
+        package_name(year, day).replace('-', "_")
+    );
+    insert_sorted(registry, |line| line.starts_with("    (2"), entry)
+}
+
+/// Scaffolds `<root>/<year>/day-<day>` from the daily template and registers it with the
+/// workspace and the solution registry. Refuses to touch a day that already exists.
+pub fn new_day(root: &Path, year: u16, day: u8) -> miette::Result<PathBuf> {
+    if !(1..=25).contains(&day) {
+        return Err(miette::miette!("day {day} is not between 1 and 25"));
+    }
+    let dir = root.join(year.to_string()).join(format!("day-{day}"));
+    if dir.exists() {
+        return Err(miette::miette!(
+            "{} already exists, refusing to overwrite it",
+            dir.display()
+        ));
+    }
+
+    let read = |path: &Path| {
+        fs::read_to_string(path)
+            .into_diagnostic()
+            .with_context(|| format!("read {}", path.display()))
+    };
+    let workspace = root.join("Cargo.toml");
+    let runner = root.join("aoc/Cargo.toml");
+    let registry = root.join("aoc/src/registry.rs");
+    // Build every edit before writing anything, so a failure leaves the tree untouched.
+    let edits = [
+        (&workspace, register_member(&read(&workspace)?, year)?),
+        (&runner, register_dependency(&read(&runner)?, year, day)?),
+        (&registry, register_solution(&read(&registry)?, year, day)?),
+    ];
+
+    for (path, template) in TEMPLATE {
+        let path = dir.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).into_diagnostic()?;
+        }
+        fs::write(&path, render(template, year, day))
+            .into_diagnostic()
+            .with_context(|| format!("write {}", path.display()))?;
+    }
+    for (path, contents) in edits {
+        fs::write(path, contents)
+            .into_diagnostic()
+            .with_context(|| format!("write {}", path.display()))?;
+    }
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let rendered = render(
+            "use {{crate_name}}::*; // {{project-name}}, todo!(\"day {{day}} - part 1\")",
+            2024,
+            6,
+        );
+        assert_eq!(
+            "use aoc2024_day06::*; // aoc2024-day06, todo!(\"day 6 - part 1\")",
+            rendered
+        );
+    }
+
+    #[test]
+    fn test_register_member() -> miette::Result<()> {
+        let manifest =
+            "[workspace]\nmembers = [\"aoc\", \"2024/day-*\"]\ndefault-members = [\"aoc\"]\n";
+        let registered = register_member(manifest, 2025)?;
+        assert_eq!(
+            "[workspace]\nmembers = [\"aoc\", \"2024/day-*\", \"2025/day-*\"]\ndefault-members = [\"aoc\", \"2025/day-*\"]\n",
+            registered
+        );
+        assert_eq!(registered, register_member(&registered, 2025)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_solution() -> miette::Result<()> {
+        let registry =
+            "solutions! {\n    (2024, 9) => aoc2024_day09,\n    (2024, 11) => aoc2024_day11,\n}\n";
+        let registered = register_solution(registry, 2024, 10)?;
+        assert_eq!(
+            "solutions! {\n    (2024, 9) => aoc2024_day09,\n    (2024, 10) => aoc2024_day10,\n    (2024, 11) => aoc2024_day11,\n}\n",
+            registered
+        );
+        assert!(register_solution(&registered, 2024, 10).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_refuses_existing_day() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let err = new_day(&root, 2024, 1).unwrap_err();
+        assert!(err.to_string().contains("refusing to overwrite"));
+        assert!(new_day(&root, 2024, 26).is_err());
+    }
+}
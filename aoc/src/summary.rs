@@ -0,0 +1,177 @@
+use std::{cmp::Reverse, fmt, time::Duration};
+
+/// How long one step of a run took: a day's parse, or one of its parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub name: String,
+    /// `None` when the step failed, panicked or timed out.
+    pub elapsed: Option<Duration>,
+    /// Whether a worker that had timed out was still running while this step was timed,
+    /// taking CPU time from it.
+    pub contaminated: bool,
+}
+
+/// Every step of a run, slowest first, checked against a total time budget.
+#[derive(Debug, Clone)]
+pub struct Summary {
+    timings: Vec<Timing>,
+    total: Duration,
+    budget: Duration,
+    /// How many of the slowest steps would have to go to bring the total within budget.
+    offenders: usize,
+}
+
+impl Summary {
+    pub fn new(mut timings: Vec<Timing>, budget: Duration) -> Self {
+        // Unfinished steps sort last, since they have no time to compare.
+        timings.sort_by_key(|timing| Reverse(timing.elapsed));
+        let total: Duration = timings.iter().filter_map(|timing| timing.elapsed).sum();
+        let mut remaining = total;
+        let mut offenders = 0;
+        for elapsed in timings.iter().map_while(|timing| timing.elapsed) {
+            if remaining <= budget {
+                break;
+            }
+            remaining -= elapsed;
+            offenders += 1;
+        }
+        Summary {
+            timings,
+            total,
+            budget,
+            offenders,
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    pub fn within_budget(&self) -> bool {
+        self.total <= self.budget
+    }
+
+    /// The slowest steps, just enough of them that the rest fit within the budget.
+    pub fn offenders(&self) -> &[Timing] {
+        &self.timings[..self.offenders]
+    }
+
+    fn unfinished(&self) -> usize {
+        self.timings
+            .iter()
+            .filter(|timing| timing.elapsed.is_none())
+            .count()
+    }
+
+    fn contaminated(&self) -> usize {
+        self.timings
+            .iter()
+            .filter(|timing| timing.elapsed.is_some() && timing.contaminated)
+            .count()
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .timings
+            .iter()
+            .map(|timing| timing.name.len())
+            .max()
+            .unwrap_or_default();
+        writeln!(f, "{:width$}  {:>12}  {:>6}", "step", "time", "share")?;
+        for (index, timing) in self.timings.iter().enumerate() {
+            let Some(elapsed) = timing.elapsed else {
+                writeln!(f, "{:width$}  {:>12}", timing.name, "did not finish")?;
+                continue;
+            };
+            let share = elapsed.as_secs_f64() / self.total.as_secs_f64().max(f64::MIN_POSITIVE);
+            write!(
+                f,
+                "{:width$}  {:>9.3} ms  {:>5.1}%",
+                timing.name,
+                elapsed.as_secs_f64() * 1e3,
+                share * 100.0
+            )?;
+            if timing.contaminated {
+                write!(f, "  *")?;
+            }
+            if index < self.offenders {
+                write!(f, "  <- over budget")?;
+            }
+            writeln!(f)?;
+        }
+        write!(f, "total {:.3} ms", self.total.as_secs_f64() * 1e3)?;
+        if self.within_budget() {
+            write!(f, ", within the {:?} budget", self.budget)?;
+        } else {
+            write!(
+                f,
+                ", {:.3} ms over the {:?} budget",
+                (self.total - self.budget).as_secs_f64() * 1e3,
+                self.budget
+            )?;
+        }
+        match self.unfinished() {
+            0 => {}
+            unfinished => write!(f, ", not counting {unfinished} unfinished step(s)")?,
+        }
+        match self.contaminated() {
+            0 => Ok(()),
+            contaminated => write!(
+                f,
+                "\n* {contaminated} step(s) timed while a timed-out step was still running"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(name: &str, millis: Option<u64>) -> Timing {
+        Timing {
+            name: name.to_string(),
+            elapsed: millis.map(Duration::from_millis),
+            contaminated: false,
+        }
+    }
+
+    #[test]
+    fn test_offenders() {
+        let timings = vec![
+            timing("2024 day 1 part 1", Some(100)),
+            timing("2024 day 6 part 2", Some(900)),
+            timing("2024 day 14 part 2", None),
+            timing("2024 day 7 part 2", Some(300)),
+        ];
+        let summary = Summary::new(timings.clone(), Duration::from_secs(1));
+        assert_eq!(Duration::from_millis(1300), summary.total());
+        assert!(!summary.within_budget());
+        assert_eq!(&timings[1..2], summary.offenders());
+        let table = summary.to_string();
+        let mut lines = table.lines().skip(1);
+        assert!(lines.next().unwrap().starts_with("2024 day 6 part 2"));
+        assert!(lines.next().unwrap().starts_with("2024 day 7 part 2"));
+        assert!(table.ends_with("300.000 ms over the 1s budget, not counting 1 unfinished step(s)"));
+
+        let summary = Summary::new(timings, Duration::from_secs(2));
+        assert!(summary.within_budget());
+        assert!(summary.offenders().is_empty());
+    }
+
+    #[test]
+    fn test_contaminated() {
+        let timings = vec![
+            timing("2024 day 14 part 2", None),
+            Timing {
+                contaminated: true,
+                ..timing("2024 day 15 part 1", Some(20))
+            },
+        ];
+        let table = Summary::new(timings, Duration::from_secs(1)).to_string();
+        assert!(table.lines().nth(1).unwrap().ends_with("100.0%  *"));
+        assert!(table.ends_with("\n* 1 step(s) timed while a timed-out step was still running"));
+    }
+}
@@ -0,0 +1,177 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Wraps the system allocator, counting allocations, bytes allocated and the peak of live
+/// bytes. Install it with `#[global_allocator]` and call [`CountingAlloc::reset`] before
+/// the code to measure.
+#[derive(Debug, Default)]
+pub struct CountingAlloc {
+    allocations: AtomicUsize,
+    bytes: AtomicUsize,
+    live: AtomicUsize,
+    peak: AtomicUsize,
+    /// Live bytes at the last reset, so the peak only covers what was allocated since.
+    baseline: AtomicUsize,
+}
+
+/// Allocator counters since the last [`CountingAlloc::reset`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Calls to `alloc` and `realloc`.
+    pub allocations: usize,
+    /// Total bytes requested, counting the full new size of every `realloc`.
+    pub bytes: usize,
+    /// Most bytes live at once above those live at the reset. Both blocks of a `realloc`
+    /// count while it runs, even when it grows in place.
+    pub peak: usize,
+}
+
+impl CountingAlloc {
+    pub const fn new() -> Self {
+        Self {
+            allocations: AtomicUsize::new(0),
+            bytes: AtomicUsize::new(0),
+            live: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+            baseline: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn reset(&self) {
+        let live = self.live.load(Ordering::Relaxed);
+        self.allocations.store(0, Ordering::Relaxed);
+        self.bytes.store(0, Ordering::Relaxed);
+        self.peak.store(live, Ordering::Relaxed);
+        self.baseline.store(live, Ordering::Relaxed);
+    }
+
+    pub fn stats(&self) -> AllocStats {
+        AllocStats {
+            allocations: self.allocations.load(Ordering::Relaxed),
+            bytes: self.bytes.load(Ordering::Relaxed),
+            peak: self
+                .peak
+                .load(Ordering::Relaxed)
+                .saturating_sub(self.baseline.load(Ordering::Relaxed)),
+        }
+    }
+
+    /// Runs `work` once from a reset and returns what it allocated.
+    pub fn measure<T>(&self, work: impl FnOnce() -> T) -> AllocStats {
+        self.reset();
+        let output = work();
+        let stats = self.stats();
+        drop(output);
+        stats
+    }
+
+    fn record_alloc(&self, size: usize) {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(size, Ordering::Relaxed);
+        let live = self.live.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak.fetch_max(live, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.live.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // A moving realloc holds the old and new blocks at once, so count the new one
+            // before releasing the old.
+            self.record_alloc(new_size);
+            self.live.fetch_sub(layout.size(), Ordering::Relaxed);
+        }
+        new_ptr
+    }
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, {} peak",
+            self.allocations,
+            Bytes(self.bytes),
+            Bytes(self.peak)
+        )
+    }
+}
+
+/// A byte count in the largest binary unit that keeps it at or above one.
+struct Bytes(usize);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut size = self.0 as f64;
+        for unit in ["B", "KiB", "MiB"] {
+            if size < 1024.0 {
+                return if unit == "B" {
+                    write!(f, "{} {unit}", self.0)
+                } else {
+                    write!(f, "{size:.1} {unit}")
+                };
+            }
+            size /= 1024.0;
+        }
+        write!(f, "{size:.1} GiB")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts_and_peak() {
+        let counter = CountingAlloc::new();
+        let small = Layout::from_size_align(16, 8).unwrap();
+        let large = Layout::from_size_align(1024, 8).unwrap();
+        unsafe {
+            let kept = counter.alloc(small);
+            counter.reset();
+            let a = counter.alloc(large);
+            let a = counter.realloc(a, large, 2048);
+            counter.dealloc(a, Layout::from_size_align(2048, 8).unwrap());
+            let b = counter.alloc(small);
+            counter.dealloc(b, small);
+            counter.dealloc(kept, small);
+        }
+        assert_eq!(
+            AllocStats {
+                allocations: 3,
+                bytes: 1024 + 2048 + 16,
+                peak: 1024 + 2048,
+            },
+            counter.stats()
+        );
+        assert_eq!(
+            "3 allocs, 3.0 KiB allocated, 3.0 KiB peak",
+            counter.stats().to_string()
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use std::{cmp::Ordering, convert::Infallible, error::Error, fmt, str::FromStr};
+
+/// What a part returns: a number, some text, or letters drawn across several lines that
+/// have to be read by eye.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    Text(String),
+    /// Rows of `#` and `.`, one per line.
+    Art(String),
+}
+
+impl Answer {
+    /// Whether the answer is a number, so it can be checked as too high or too low.
+    pub fn is_number(&self) -> bool {
+        matches!(self, Answer::Signed(_) | Answer::Unsigned(_))
+    }
+
+    /// A line naming the answer, followed by `details` such as its time. Art starts on the
+    /// next line, since letters drawn across lines only line up from the start of one.
+    pub fn line(&self, name: &str, details: &str) -> String {
+        match self {
+            Answer::Art(art) => format!("{name}: {details}\n{art}"),
+            answer => format!("{name}: {answer} {details}"),
+        }
+    }
+}
+
+/// The error a part returns until it is written, as scaffolded from the daily template.
+/// Checks against known answers skip a part that returns it instead of failing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved {
+    pub day: u8,
+    pub part: u8,
+}
+
+impl Unsolved {
+    /// Whether `report` says a part is not written yet.
+    pub fn is(report: &miette::Report) -> bool {
+        report.downcast_ref::<Unsolved>().is_some()
+    }
+}
+
+impl fmt::Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {} part {} not solved", self.day, self.part)
+    }
+}
+
+impl Error for Unsolved {}
+
+impl miette::Diagnostic for Unsolved {}
+
+macro_rules! from_integers {
+    ($variant:ident($wide:ty) <- $($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Answer::$variant(value as $wide)
+                }
+            }
+        )*
+    };
+}
+
+from_integers!(Unsigned(u128) <- u8, u16, u32, u64, u128, usize);
+from_integers!(Signed(i128) <- i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// Reads an answer written by hand, such as one from an example's sidecar: whole numbers
+/// become numbers and anything else is text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(answer: &str) -> Result<Self, Self::Err> {
+        Ok(answer
+            .parse()
+            .map(Answer::Unsigned)
+            .or_else(|_| answer.parse().map(Answer::Signed))
+            .unwrap_or_else(|_| Answer::Text(answer.to_string())))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(value) => write!(f, "{value}"),
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Text(text) | Answer::Art(text) => f.write_str(text),
+        }
+    }
+}
+
+/// Numbers compare by value whichever variant holds them, so `5u8` matches a `5` read
+/// from a sidecar. Text only matches the same text, and art the same art, never a number.
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) | (Answer::Art(a), Answer::Art(b)) => a == b,
+            _ => self.partial_cmp(other) == Some(Ordering::Equal),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+/// Only numbers are ordered, which is what a too high or too low hint needs.
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => Some(a.cmp(b)),
+            (Answer::Signed(a), Answer::Signed(b)) => Some(a.cmp(b)),
+            (Answer::Signed(a), Answer::Unsigned(b)) => Some(match u128::try_from(*a) {
+                Ok(a) => a.cmp(b),
+                Err(_) => Ordering::Less,
+            }),
+            (Answer::Unsigned(_), Answer::Signed(_)) => {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(Answer::from(5u8), Answer::from(5i64));
+        assert_eq!(Answer::from(42usize), "42".parse().unwrap());
+        assert_eq!(Answer::from(-3i32), "-3".parse().unwrap());
+        assert_eq!(Answer::from("abc"), "abc".parse().unwrap());
+        assert!(Answer::from(-1i32) < Answer::from(0u8));
+        assert!(Answer::from(u128::MAX) > Answer::from(i128::MAX));
+        assert_eq!(None, Answer::from(1u8).partial_cmp(&Answer::from("1")));
+        assert_ne!(Answer::from(1u8), Answer::from("one"));
+        assert_ne!(Answer::from(1u8), Answer::from("1"));
+        assert_ne!(Answer::from("#."), Answer::Art("#.".to_string()));
+    }
+
+    #[test]
+    fn test_line() {
+        assert_eq!(
+            "part 1: 42 (1ms)",
+            Answer::from(42u8).line("part 1", "(1ms)")
+        );
+        let art = Answer::Art("#..#\n####\n#..#".to_string());
+        assert_eq!(
+            "part 2: (1ms)\n#..#\n####\n#..#",
+            art.line("part 2", "(1ms)")
+        );
+    }
+}
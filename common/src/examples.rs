@@ -0,0 +1,121 @@
+use std::{cmp::Ordering, fs, path::Path};
+
+use miette::{Context, IntoDiagnostic};
+
+use crate::answer::{Answer, Unsolved};
+
+/// Expected answer for `part` of an example, read from the sidecar next to it: `1.txt` is
+/// answered by `1.answers`, which holds one `part1: <answer>` line per part the example
+/// applies to.
+pub fn expected(example: &Path, part: u8) -> miette::Result<Option<Answer>> {
+    let sidecar = example.with_extension("answers");
+    let answers = fs::read_to_string(&sidecar)
+        .into_diagnostic()
+        .with_context(|| format!("read {}", sidecar.display()))?;
+    let key = format!("part{part}");
+    for (index, line) in answers.lines().enumerate() {
+        let Some((name, answer)) = line.split_once(':') else {
+            continue;
+        };
+        if name.trim() == key {
+            let answer = answer.trim();
+            if answer.is_empty() {
+                return Err(miette::miette!(
+                    "{} line {}: {key} has no answer",
+                    sidecar.display(),
+                    index + 1
+                ));
+            }
+            return Ok(Some(answer.parse().expect("any answer parses")));
+        }
+    }
+    Ok(None)
+}
+
+/// Runs `process` on an example and compares it with the answer in the sidecar, saying
+/// whether a wrong number is too high or too low. Examples without an answer for `part`
+/// are skipped, and so are parts that are still [`Unsolved`], even if their answer is left
+/// blank.
+pub fn check(
+    example: &Path,
+    part: u8,
+    process: fn(&str) -> miette::Result<Answer>,
+) -> miette::Result<()> {
+    let Some(expected) = expected(example, part).transpose() else {
+        return Ok(());
+    };
+    let input = fs::read_to_string(example)
+        .into_diagnostic()
+        .with_context(|| format!("read {}", example.display()))?;
+    let answer = match process(&input) {
+        Err(report) if Unsolved::is(&report) => return Ok(()),
+        answer => answer?,
+    };
+    let expected = expected?;
+    let hint = match answer.partial_cmp(&expected) {
+        Some(Ordering::Greater) => ", too high",
+        Some(Ordering::Less) => ", too low",
+        _ => "",
+    };
+    assert_eq!(
+        expected,
+        answer,
+        "part {part} of {}{hint}",
+        example.display()
+    );
+    Ok(())
+}
+
+/// Generates one test per part for every `examples/*.txt` file of the calling day, checked
+/// against the answers in each example's sidecar. Invoke it from the crate root, behind
+/// `#[cfg(test)]`.
+#[macro_export]
+macro_rules! example_tests {
+    () => {
+        mod examples {
+            use std::path::PathBuf;
+
+            #[rstest::rstest]
+            fn part1(#[files("examples/*.txt")] example: PathBuf) -> miette::Result<()> {
+                $crate::examples::check(&example, 1, super::part1::process)
+            }
+
+            #[rstest::rstest]
+            fn part2(#[files("examples/*.txt")] example: PathBuf) -> miette::Result<()> {
+                $crate::examples::check(&example, 2, super::part2::process)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected() -> miette::Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        fs::create_dir_all(&dir).into_diagnostic()?;
+        let example = dir.join("1.txt");
+        fs::write(dir.join("1.answers"), "part1: 142\npart2:\n").into_diagnostic()?;
+        assert_eq!(Some(Answer::from(142u32)), expected(&example, 1)?);
+        assert!(expected(&example, 2).is_err());
+        fs::write(dir.join("1.answers"), "part2: 281\n").into_diagnostic()?;
+        assert_eq!(None, expected(&example, 1)?);
+        fs::remove_dir_all(&dir).into_diagnostic()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsolved_skipped() -> miette::Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-unsolved-{}", std::process::id()));
+        fs::create_dir_all(&dir).into_diagnostic()?;
+        let example = dir.join("1.txt");
+        fs::write(&example, "1abc2\n").into_diagnostic()?;
+        fs::write(dir.join("1.answers"), "part1:\n").into_diagnostic()?;
+        check(&example, 1, |_| Err(Unsolved { day: 1, part: 1 }.into()))?;
+        assert!(check(&example, 1, |_| Ok(Answer::from(12u8))).is_err());
+        fs::remove_dir_all(&dir).into_diagnostic()?;
+        Ok(())
+    }
+}
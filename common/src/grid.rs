@@ -0,0 +1,146 @@
+use std::fmt;
+
+use glam::IVec2;
+
+/// Moves to the four orthogonal neighbours.
+pub const ORTHOGONAL: [IVec2; 4] = [IVec2::NEG_Y, IVec2::X, IVec2::Y, IVec2::NEG_X];
+
+/// Moves to all eight neighbours, orthogonal and diagonal.
+pub const ALL_DIRECTIONS: [IVec2; 8] = [
+    IVec2::NEG_Y,
+    IVec2::new(1, -1),
+    IVec2::X,
+    IVec2::ONE,
+    IVec2::Y,
+    IVec2::new(-1, 1),
+    IVec2::NEG_X,
+    IVec2::NEG_ONE,
+];
+
+/// A rectangular grid of cells addressed by `IVec2` positions, with `(0, 0)` top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    size: IVec2,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses one cell per character, one row per line.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> miette::Result<T>,
+    ) -> miette::Result<Self> {
+        let mut cells = vec![];
+        let mut size = IVec2::ZERO;
+        for (y, line) in input.lines().enumerate() {
+            let width = line.chars().count() as i32;
+            if y == 0 {
+                size.x = width;
+            } else if width != size.x {
+                return Err(miette::miette!(
+                    "line {} is {width} cells wide, expected {}",
+                    y + 1,
+                    size.x
+                ));
+            }
+            for c in line.chars() {
+                cells.push(cell(c)?);
+            }
+            size.y += 1;
+        }
+        Ok(Grid { size, cells })
+    }
+
+    pub fn from_fn(size: IVec2, mut cell: impl FnMut(IVec2) -> T) -> Self {
+        let cells = (0..size.y)
+            .flat_map(|y| (0..size.x).map(move |x| IVec2::new(x, y)))
+            .map(&mut cell)
+            .collect();
+        Grid { size, cells }
+    }
+
+    pub fn size(&self) -> IVec2 {
+        self.size
+    }
+
+    pub fn contains(&self, pos: IVec2) -> bool {
+        pos.cmpge(IVec2::ZERO).all() && pos.cmplt(self.size).all()
+    }
+
+    fn index(&self, pos: IVec2) -> Option<usize> {
+        self.contains(pos)
+            .then(|| (pos.y * self.size.x + pos.x) as usize)
+    }
+
+    pub fn get(&self, pos: IVec2) -> Option<&T> {
+        self.index(pos).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, pos: IVec2) -> Option<&mut T> {
+        self.index(pos).map(|index| &mut self.cells[index])
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = IVec2> {
+        let size = self.size;
+        (0..size.y).flat_map(move |y| (0..size.x).map(move |x| IVec2::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (IVec2, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Positions of the cells for which `f` returns true.
+    pub fn find_all<'a>(&'a self, f: impl Fn(&T) -> bool + 'a) -> impl Iterator<Item = IVec2> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| f(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// In-bounds positions reached by applying each of `moves` to `pos`.
+    pub fn neighbours<'a>(
+        &'a self,
+        pos: IVec2,
+        moves: &'a [IVec2],
+    ) -> impl Iterator<Item = IVec2> + 'a {
+        moves
+            .iter()
+            .map(move |mv| pos + *mv)
+            .filter(|next| self.contains(*next))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.cells.chunks(self.size.x.max(1) as usize).enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            row.iter().try_for_each(|cell| write!(f, "{cell}"))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> miette::Result<()> {
+        let grid = Grid::parse("#.\n.#\n..", Ok)?;
+        assert_eq!(IVec2::new(2, 3), grid.size());
+        assert_eq!(Some(&'#'), grid.get(IVec2::new(1, 1)));
+        assert_eq!(None, grid.get(IVec2::new(2, 0)));
+        assert_eq!(
+            vec![IVec2::ZERO, IVec2::ONE],
+            grid.find_all(|c| *c == '#').collect::<Vec<_>>()
+        );
+        assert_eq!("#.\n.#\n..", grid.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        assert!(Grid::parse("##\n#", Ok).is_err());
+    }
+}
@@ -0,0 +1,9 @@
+pub mod alloc;
+pub mod answer;
+pub mod examples;
+pub mod grid;
+pub mod puzzle;
+pub mod scaling;
+pub mod search;
+#[cfg(feature = "proptest")]
+pub mod strategy;
@@ -0,0 +1,59 @@
+use crate::answer::Answer;
+
+/// A generated puzzle input, with whichever answers were cheap to work out while building
+/// it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Puzzle {
+    pub input: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Puzzle {
+    pub fn new(input: String) -> Self {
+        Puzzle {
+            input,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_part1(mut self, answer: impl Into<Answer>) -> Self {
+        self.part1 = Some(answer.into());
+        self
+    }
+
+    pub fn with_part2(mut self, answer: impl Into<Answer>) -> Self {
+        self.part2 = Some(answer.into());
+        self
+    }
+
+    /// The known answer to `part` (1 or 2), if any.
+    pub fn answer(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// The known answers as an example sidecar, one `partN: answer` line each.
+    pub fn answers(&self) -> String {
+        [1, 2]
+            .into_iter()
+            .filter_map(|part| Some(format!("part{part}: {}\n", self.answer(part)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers() {
+        let puzzle = Puzzle::new("1 2\n".to_string()).with_part2(3);
+        assert_eq!(None, puzzle.answer(1));
+        assert_eq!(Some(&Answer::from(3)), puzzle.answer(2));
+        assert_eq!("part2: 3\n", puzzle.answers());
+    }
+}
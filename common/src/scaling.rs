@@ -0,0 +1,165 @@
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use crate::answer::{Answer, Unsolved};
+
+/// A day's `process` for one part.
+pub type Process = fn(&'static str) -> miette::Result<Answer>;
+
+/// A candidate for how a part's running time grows with the size of its input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Growth {
+    Linear,
+    Linearithmic,
+    Quadratic,
+}
+
+impl Growth {
+    const ALL: [Growth; 3] = [Growth::Linear, Growth::Linearithmic, Growth::Quadratic];
+
+    fn model(self, n: f64) -> f64 {
+        match self {
+            Growth::Linear => n,
+            Growth::Linearithmic => n * n.log2(),
+            Growth::Quadratic => n * n,
+        }
+    }
+}
+
+impl fmt::Display for Growth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Growth::Linear => "n",
+            Growth::Linearithmic => "n log n",
+            Growth::Quadratic => "n²",
+        })
+    }
+}
+
+/// The growth model closest to a set of timings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fit {
+    pub growth: Growth,
+    /// Root mean square of the log residuals: how far off the best-fitting constant
+    /// factor is, on average, as a ratio. `0.05` is about 5%.
+    pub error: f64,
+    /// Slope of log time against log size, 1 for linear and 2 for quadratic.
+    pub exponent: f64,
+}
+
+/// Fits `time = c * model(size)` for every [`Growth`] in log space, where the constant
+/// factor drops out as a mean, and picks the model with the smallest residuals.
+pub fn fit(samples: &[(usize, Duration)]) -> Fit {
+    assert!(samples.len() >= 2, "need at least two sizes to fit growth");
+    let points: Vec<(f64, f64)> = samples
+        .iter()
+        .map(|(size, time)| (*size as f64, time.as_secs_f64().max(f64::MIN_POSITIVE)))
+        .collect();
+    let count = points.len() as f64;
+
+    let (growth, error) = Growth::ALL
+        .into_iter()
+        .map(|growth| {
+            let residuals: Vec<f64> = points
+                .iter()
+                .map(|&(n, t)| t.ln() - growth.model(n).ln())
+                .collect();
+            let mean = residuals.iter().sum::<f64>() / count;
+            let variance = residuals.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / count;
+            (growth, variance.sqrt())
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("there is more than one model");
+
+    let mean_x = points.iter().map(|(n, _)| n.ln()).sum::<f64>() / count;
+    let mean_y = points.iter().map(|(_, t)| t.ln()).sum::<f64>() / count;
+    let (covariance, spread) = points.iter().fold((0.0, 0.0), |(cov, spread), (n, t)| {
+        let dx = n.ln() - mean_x;
+        (cov + dx * (t.ln() - mean_y), spread + dx * dx)
+    });
+
+    Fit {
+        growth,
+        error: error.exp() - 1.0,
+        exponent: covariance / spread,
+    }
+}
+
+/// Fastest of a few runs of `process`, repeating until at least 50ms have been spent.
+fn time(process: Process, input: &'static str) -> Duration {
+    let mut fastest = Duration::MAX;
+    let start = Instant::now();
+    for run in 0.. {
+        if run >= 3 && start.elapsed() > Duration::from_millis(50) {
+            break;
+        }
+        let begin = Instant::now();
+        process(input).expect("generated inputs are valid");
+        fastest = fastest.min(begin.elapsed());
+    }
+    fastest
+}
+
+/// Times each part on an input of every size from `generate` and prints the growth model
+/// that best fits it. A part that is not written yet is skipped. Meant to run after
+/// `divan::main()` in a scaling bench.
+pub fn report(sizes: &[usize], generate: impl Fn(usize) -> String, parts: &[(&str, Process)]) {
+    let inputs: Vec<(usize, &'static str)> = sizes
+        .iter()
+        .map(|&size| (size, &*generate(size).leak()))
+        .collect();
+    println!();
+    for (name, process) in parts {
+        if let Some(Err(report)) = inputs.first().map(|&(_, input)| process(input)) {
+            if Unsolved::is(&report) {
+                println!("{name}: skipped, {report}");
+                continue;
+            }
+        }
+        let samples: Vec<(usize, Duration)> = inputs
+            .iter()
+            .map(|&(size, input)| (size, time(*process, input)))
+            .collect();
+        let fit = fit(&samples);
+        println!(
+            "{name}: grows as {} (n^{:.2}, {:.0}% off)",
+            fit.growth,
+            fit.exponent,
+            fit.error * 100.0
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples(growth: Growth) -> Vec<(usize, Duration)> {
+        [1_000, 2_000, 4_000, 8_000, 16_000]
+            .into_iter()
+            .map(|n| (n, Duration::from_secs_f64(growth.model(n as f64) * 1e-6)))
+            .collect()
+    }
+
+    #[test]
+    fn test_fit() {
+        for growth in Growth::ALL {
+            let fit = fit(&samples(growth));
+            assert_eq!(growth, fit.growth);
+            assert!(fit.error < 1e-4, "{fit:?}");
+        }
+        assert!((fit(&samples(Growth::Linear)).exponent - 1.0).abs() < 1e-6);
+        assert!((fit(&samples(Growth::Quadratic)).exponent - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_report_skips_unsolved() {
+        report(
+            &[1, 2],
+            |size| "x".repeat(size),
+            &[("part1", |_| Err(Unsolved { day: 1, part: 1 }.into()))],
+        );
+    }
+}
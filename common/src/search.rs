@@ -0,0 +1,353 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use glam::IVec2;
+
+use crate::grid::{Grid, ORTHOGONAL};
+
+/// A graph that can list the moves out of each node.
+pub trait Neighbours {
+    type Node: Copy + Eq + Hash;
+
+    /// Nodes reachable in one move from `node`, with the cost of each move.
+    fn neighbours(&self, node: Self::Node) -> impl Iterator<Item = (Self::Node, u64)>;
+}
+
+/// Adjacency lists of `(node, cost)` edges.
+impl<N: Copy + Eq + Hash> Neighbours for HashMap<N, Vec<(N, u64)>> {
+    type Node = N;
+
+    fn neighbours(&self, node: N) -> impl Iterator<Item = (N, u64)> {
+        self.get(&node).into_iter().flatten().copied()
+    }
+}
+
+type Passable<'a, T> = Box<dyn Fn(&T) -> bool + 'a>;
+type MoveCost<'a, T> = Box<dyn Fn(&T, &T) -> Option<u64> + 'a>;
+
+/// A view of a [`Grid`] as a graph, where every passable cell is a node.
+pub struct GridGraph<'a, T> {
+    grid: &'a Grid<T>,
+    moves: &'a [IVec2],
+    passable: Passable<'a, T>,
+    cost: MoveCost<'a, T>,
+}
+
+impl<T> Grid<T> {
+    /// Every cell is passable and every orthogonal move costs 1 until configured otherwise.
+    pub fn graph(&self) -> GridGraph<'_, T> {
+        GridGraph {
+            grid: self,
+            moves: &ORTHOGONAL,
+            passable: Box::new(|_| true),
+            cost: Box::new(|_, _| Some(1)),
+        }
+    }
+}
+
+impl<'a, T> GridGraph<'a, T> {
+    pub fn moves(mut self, moves: &'a [IVec2]) -> Self {
+        self.moves = moves;
+        self
+    }
+
+    pub fn passable(mut self, passable: impl Fn(&T) -> bool + 'a) -> Self {
+        self.passable = Box::new(passable);
+        self
+    }
+
+    /// Cost of moving from one cell to another, or `None` if the move is not allowed.
+    pub fn cost(mut self, cost: impl Fn(&T, &T) -> Option<u64> + 'a) -> Self {
+        self.cost = Box::new(cost);
+        self
+    }
+}
+
+impl<T> Neighbours for GridGraph<'_, T> {
+    type Node = IVec2;
+
+    fn neighbours(&self, node: IVec2) -> impl Iterator<Item = (IVec2, u64)> {
+        let from = self.grid.get(node);
+        self.grid
+            .neighbours(node, self.moves)
+            .filter_map(move |next| {
+                let to = self.grid.get(next)?;
+                if !(self.passable)(to) {
+                    return None;
+                }
+                (self.cost)(from?, to).map(|cost| (next, cost))
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub nodes: Vec<N>,
+    pub cost: u64,
+}
+
+fn rebuild_path<N: Copy + Eq + Hash>(came_from: &HashMap<N, N>, end: N, cost: u64) -> Path<N> {
+    let mut nodes = vec![end];
+    while let Some(previous) = came_from.get(nodes.last().unwrap()) {
+        nodes.push(*previous);
+    }
+    nodes.reverse();
+    Path { nodes, cost }
+}
+
+/// Shortest path by number of moves, ignoring move costs.
+pub fn bfs<G: Neighbours>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    let mut came_from = HashMap::new();
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, steps)) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(rebuild_path(&came_from, node, steps));
+        }
+        for (next, _) in graph.neighbours(node) {
+            if seen.insert(next) {
+                came_from.insert(next, node);
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Number of moves to every node reachable from `start`, ignoring move costs.
+pub fn bfs_distances<G: Neighbours>(graph: &G, start: G::Node) -> HashMap<G::Node, u64> {
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let steps = distances[&node];
+        for (next, _) in graph.neighbours(node) {
+            distances.entry(next).or_insert_with(|| {
+                queue.push_back(next);
+                steps + 1
+            });
+        }
+    }
+    distances
+}
+
+/// Heap entry ordered so that the lowest priority is popped first.
+struct Frontier<N> {
+    priority: u64,
+    cost: u64,
+    node: N,
+}
+
+impl<N> PartialEq for Frontier<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N> Eq for Frontier<N> {}
+
+impl<N> PartialOrd for Frontier<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Frontier<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Cheapest path to a goal, guided by a `heuristic` that must never overestimate the
+/// remaining cost.
+pub fn astar<G: Neighbours>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> u64,
+) -> Option<Path<G::Node>> {
+    let mut came_from = HashMap::new();
+    let mut best = HashMap::from([(start, 0)]);
+    let mut frontier = BinaryHeap::from([Frontier {
+        priority: heuristic(&start),
+        cost: 0,
+        node: start,
+    }]);
+    while let Some(Frontier { cost, node, .. }) = frontier.pop() {
+        if best.get(&node).is_some_and(|&best| cost > best) {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some(rebuild_path(&came_from, node, cost));
+        }
+        for (next, step) in graph.neighbours(node) {
+            let cost = cost + step;
+            if best.get(&next).is_none_or(|&best| cost < best) {
+                best.insert(next, cost);
+                came_from.insert(next, node);
+                frontier.push(Frontier {
+                    priority: cost + heuristic(&next),
+                    cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Cheapest path to a goal.
+pub fn dijkstra<G: Neighbours>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    astar(graph, start, is_goal, |_| 0)
+}
+
+/// Cheapest cost to every node reachable from `start`.
+pub fn dijkstra_distances<G: Neighbours>(graph: &G, start: G::Node) -> HashMap<G::Node, u64> {
+    let mut best = HashMap::from([(start, 0)]);
+    let mut frontier = BinaryHeap::from([Frontier {
+        priority: 0,
+        cost: 0,
+        node: start,
+    }]);
+    while let Some(Frontier { cost, node, .. }) = frontier.pop() {
+        if best.get(&node).is_some_and(|&best| cost > best) {
+            continue;
+        }
+        for (next, step) in graph.neighbours(node) {
+            let cost = cost + step;
+            if best.get(&next).is_none_or(|&best| cost < best) {
+                best.insert(next, cost);
+                frontier.push(Frontier {
+                    priority: cost,
+                    cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    best
+}
+
+/// Number of distinct paths from `start` that end at their first goal node, or `None` if a
+/// cycle is reachable (so there would be infinitely many).
+pub fn count_paths<G: Neighbours>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<u64> {
+    let mut counts: HashMap<G::Node, u64> = HashMap::new();
+    let mut in_progress = HashSet::new();
+    let mut stack = vec![(start, false)];
+    while let Some((node, expanded)) = stack.pop() {
+        if expanded {
+            let count = if is_goal(&node) {
+                1
+            } else {
+                graph.neighbours(node).map(|(next, _)| counts[&next]).sum()
+            };
+            counts.insert(node, count);
+            in_progress.remove(&node);
+            continue;
+        }
+        if counts.contains_key(&node) {
+            continue;
+        }
+        // Reaching a node again while its own descendants are being explored means a cycle.
+        if !in_progress.insert(node) {
+            return None;
+        }
+        stack.push((node, true));
+        if !is_goal(&node) {
+            stack.extend(
+                graph
+                    .neighbours(node)
+                    .filter(|(next, _)| !counts.contains_key(next))
+                    .map(|(next, _)| (next, false)),
+            );
+        }
+    }
+    counts.get(&start).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+    fn maze() -> Grid<char> {
+        Grid::parse(MAZE, Ok).unwrap()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze();
+        let graph = grid.graph().passable(|c| *c != '#');
+        let end = grid.find_all(|c| *c == 'E').next().unwrap();
+        let path = bfs(&graph, IVec2::ZERO, |pos| *pos == end).unwrap();
+        assert_eq!(15, path.cost);
+        assert_eq!(16, path.nodes.len());
+        assert_eq!(Some(&end), path.nodes.last());
+        assert_eq!(Some(&15), bfs_distances(&graph, IVec2::ZERO).get(&end));
+        assert_eq!(None, bfs(&graph, IVec2::ZERO, |pos| pos.x > 100));
+    }
+
+    #[test]
+    fn test_weighted() {
+        let grid = Grid::parse("19111\n11191\n99911", |c| {
+            c.to_digit(10)
+                .map(u64::from)
+                .ok_or_else(|| miette::miette!("bad cost {c}"))
+        })
+        .unwrap();
+        let graph = grid.graph().cost(|_, to| Some(*to));
+        let end = IVec2::new(4, 2);
+        let path = dijkstra(&graph, IVec2::ZERO, |pos| *pos == end).unwrap();
+        assert_eq!(8, path.cost);
+        let heuristic = |pos: &IVec2| (end - *pos).abs().element_sum() as u64;
+        assert_eq!(
+            Some(path.cost),
+            astar(&graph, IVec2::ZERO, |pos| *pos == end, heuristic).map(|p| p.cost)
+        );
+        assert_eq!(Some(&8), dijkstra_distances(&graph, IVec2::ZERO).get(&end));
+    }
+
+    #[test]
+    fn test_count_paths() {
+        let grid = Grid::parse("0123\n1234\n2345", |c| Ok(c.to_digit(10).unwrap())).unwrap();
+        let uphill = grid.graph().cost(|from, to| (*to == from + 1).then_some(1));
+        assert_eq!(
+            Some(10),
+            count_paths(&uphill, IVec2::ZERO, |pos| grid.get(*pos) == Some(&5))
+        );
+        let any = grid.graph();
+        assert_eq!(None, count_paths(&any, IVec2::ZERO, |_| false));
+    }
+
+    #[test]
+    fn test_adjacency_graph() {
+        let graph = HashMap::from([
+            ('a', vec![('b', 1), ('c', 5)]),
+            ('b', vec![('c', 1)]),
+            ('c', vec![]),
+        ]);
+        let path = dijkstra(&graph, 'a', |node| *node == 'c').unwrap();
+        assert_eq!(vec!['a', 'b', 'c'], path.nodes);
+        assert_eq!(2, path.cost);
+        assert_eq!(Some(2), count_paths(&graph, 'a', |node| *node == 'c'));
+    }
+}
@@ -0,0 +1,37 @@
+use proptest::{collection::vec, prelude::*};
+
+/// Lines generated by `line`, joined with newlines.
+pub fn lines(
+    line: impl Strategy<Value = String>,
+    count: std::ops::Range<usize>,
+) -> impl Strategy<Value = String> {
+    vec(line, count).prop_map(|lines| lines.join("\n"))
+}
+
+/// A rectangular grid of `cell` characters, one row per line.
+pub fn grid(
+    width: std::ops::Range<usize>,
+    height: std::ops::Range<usize>,
+    cell: impl Strategy<Value = char> + Clone,
+) -> impl Strategy<Value = String> {
+    (width, height).prop_flat_map(move |(width, height)| {
+        lines(
+            vec(cell.clone(), width).prop_map(String::from_iter),
+            height..height + 1,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_grid_is_rectangular(grid in grid(1..8, 1..8, prop::char::range('a', 'c'))) {
+            let widths: Vec<usize> = grid.lines().map(str::len).collect();
+            prop_assert!(widths.iter().all(|&width| width == widths[0]));
+            prop_assert!(grid.chars().all(|c| c == '\n' || ('a'..='c').contains(&c)));
+        }
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_common::puzzle::Puzzle;
+
+/// A puzzle input of about `size` items built from `seed`, with any answers that are
+/// cheap to know while building it. Empty, with no answers, until it is written.
+pub fn puzzle(_size: usize, _seed: u64) -> Puzzle {
+    Puzzle::default()
+}
@@ -0,0 +1,14 @@
+pub mod generate;
+pub mod part1;
+pub mod part2;
+
+#[cfg(test)]
+aoc_common::example_tests!();
+
+/// The puzzle input, parsed once and shared by both parts.
+pub type Input = Vec<String>;
+
+#[tracing::instrument(skip(input), fields(bytes = input.len(), lines = input.lines().count()))]
+pub fn parse(input: &str) -> miette::Result<Input> {
+    Ok(input.lines().map(str::to_string).collect())
+}
@@ -0,0 +1,17 @@
+use aoc_common::answer::{Answer, Unsolved};
+
+use crate::{parse, Input};
+
+#[tracing::instrument(skip_all)]
+pub fn solve(_input: &Input) -> miette::Result<Answer> {
+    Err(Unsolved {
+        day: {{day}},
+        part: 1,
+    }
+    .into())
+}
+
+#[tracing::instrument(skip_all)]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    solve(&parse(input)?)
+}